@@ -8,7 +8,7 @@ use oxc::{
 };
 use rolldown_common::ImportKind;
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::utils::{call_expression_ext::CallExpressionExt, new_expression_ext::NewExpressionExt};
 
 use super::{side_effect_detector::SideEffectDetector, AstScanner};
 
@@ -103,4 +103,21 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
 
     walk::walk_call_expression(self, expr);
   }
+
+  fn visit_new_expression(&mut self, expr: &oxc::ast::ast::NewExpression<'ast>) {
+    if let Some((new_url, url)) = expr.new_worker_with_url(self.scopes) {
+      let id = self.add_import_record(url.value.as_str(), ImportKind::Worker);
+      self.result.imports.insert(new_url.span, id);
+      // Skip the `new URL(...)` argument, which is already recorded as the worker
+      expr.arguments.iter().skip(1).for_each(|arg| self.visit_argument(arg));
+      return;
+    }
+
+    if let Some(url) = expr.new_url_with_import_meta(self.scopes) {
+      let id = self.add_import_record(url.value.as_str(), ImportKind::NewUrl);
+      self.result.imports.insert(expr.span, id);
+    }
+
+    walk::walk_new_expression(self, expr);
+  }
 }
@@ -8,6 +8,8 @@ use oxc::ast::Trivias;
 use rolldown_common::AstScopes;
 use rustc_hash::FxHashSet;
 
+use crate::utils::lower_decorators::{class_span_with_decorators, is_decorated_class};
+
 mod annotation;
 mod utils;
 
@@ -50,6 +52,12 @@ impl<'a> SideEffectDetector<'a> {
 
   fn detect_side_effect_of_class(&mut self, cls: &oxc::ast::ast::Class) -> bool {
     use oxc::ast::ast::ClassElement;
+    // Decorators are arbitrary function calls, unless the class is annotated with `/* @__PURE__ */`.
+    if is_decorated_class(cls)
+      && !self.is_pure_function_or_constructor_call(class_span_with_decorators(cls))
+    {
+      return true;
+    }
     cls.body.body.iter().any(|elm| match elm {
       ClassElement::StaticBlock(static_block) => {
         static_block.body.iter().any(|stmt| self.detect_side_effect_of_stmt(stmt))
@@ -632,4 +640,18 @@ mod test {
     assert!(get_statements_side_effect("const { url } = import.meta"));
     assert!(get_statements_side_effect("import.meta.url = 'test'"));
   }
+
+  #[test]
+  fn test_decorated_class() {
+    assert!(!get_statements_side_effect("class Foo { method() {} }"));
+    assert!(get_statements_side_effect("@dec class Foo {}"));
+    assert!(get_statements_side_effect("export default @dec class {}"));
+    assert!(get_statements_side_effect("class Foo { @dec method() {} }"));
+    assert!(get_statements_side_effect("class Foo { @dec static field = 1 }"));
+    assert!(get_statements_side_effect("const Foo = class { @dec accessor x }"));
+    assert!(!get_statements_side_effect("/* @__PURE__ */ @dec class Foo {}"));
+    assert!(!get_statements_side_effect(
+      "const Foo = /* @__PURE__ */ @dec class { @dec method() {} }"
+    ));
+  }
 }
@@ -8,12 +8,13 @@ use crate::{
   bundler_builder::BundlerBuilder,
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
   types::bundle_output::BundleOutput,
+  utils::third_party_licenses::{collect_third_party_licenses, render_license_report},
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
 use rolldown_common::SharedFileEmitter;
 use rolldown_error::BuildError;
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{
   HookBuildEndArgs, HookRenderErrorArgs, PluginDriver, SharedPlugin, SharedPluginDriver,
 };
@@ -22,9 +23,10 @@ use tracing_chrome::FlushGuard;
 pub struct Bundler {
   pub(crate) options: SharedOptions,
   pub(crate) plugin_driver: SharedPluginDriver,
-  pub(crate) fs: OsFileSystem,
+  pub(crate) fs: SharedFileSystem,
   pub(crate) resolver: SharedResolver,
   pub(crate) file_emitter: SharedFileEmitter,
+  pub(crate) invalid_options: Vec<(&'static str, String)>,
   pub(crate) _log_guard: Option<FlushGuard>,
 }
 
@@ -75,15 +77,26 @@ impl Bundler {
   pub async fn scan(&mut self) -> Result<ScanStageOutput> {
     self.plugin_driver.build_start().await?;
 
-    let scan_stage_output = ScanStage::new(
+    let mut scan_stage_output = ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
-      self.fs,
+      self.fs.clone(),
       Arc::clone(&self.resolver),
+      Arc::clone(&self.file_emitter),
     )
     .scan()
     .await;
 
+    if let Ok(output) = &mut scan_stage_output {
+      output.errors.splice(
+        0..0,
+        self
+          .invalid_options
+          .iter()
+          .map(|(option, explanation)| BuildError::invalid_option(*option, explanation.as_str())),
+      );
+    }
+
     let args = Self::normalize_error(&scan_stage_output, |ret| &ret.errors)
       .map(|error| HookBuildEndArgs { error });
 
@@ -122,6 +135,12 @@ impl Bundler {
       bundle_output?
     };
 
+    output.licenses =
+      collect_third_party_licenses(&link_stage_output.module_table.modules, &self.fs);
+    if let Some(license_report) = &self.options.license_report {
+      output.assets.push(render_license_report(license_report, &output.licenses));
+    }
+
     // Add additional files from build plugins.
     self.file_emitter.add_additional_files(&mut output.assets);
 
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use rolldown_common::FileEmitter;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{PluginDriver, SharedPlugin};
 use rolldown_resolver::Resolver;
 
@@ -14,16 +14,18 @@ use crate::{
 pub struct BundlerBuilder {
   input_options: BundlerOptions,
   plugins: Vec<SharedPlugin>,
+  fs: SharedFileSystem,
 }
 
 impl BundlerBuilder {
   pub fn build(self) -> Bundler {
     let maybe_guard = rolldown_tracing::try_init_tracing();
 
-    let NormalizeOptionsReturn { options, resolve_options } = normalize_options(self.input_options);
+    let NormalizeOptionsReturn { options, resolve_options, invalid_options } =
+      normalize_options(self.input_options, &self.fs);
 
     let resolver: SharedResolver =
-      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), self.fs.clone()).into();
 
     let options = Arc::new(options);
 
@@ -34,7 +36,8 @@ impl BundlerBuilder {
       file_emitter,
       resolver,
       options,
-      fs: OsFileSystem,
+      invalid_options,
+      fs: self.fs,
       _log_guard: maybe_guard,
     }
   }
@@ -50,4 +53,12 @@ impl BundlerBuilder {
     self.plugins = plugins;
     self
   }
+
+  /// Use `fs` instead of the OS file system for resolving, loading and writing files, e.g. a
+  /// `rolldown_fs::MemoryFileSystem` to run builds entirely in memory.
+  #[must_use]
+  pub fn with_fs(mut self, fs: impl FileSystem + 'static) -> Self {
+    self.fs = SharedFileSystem::new(fs);
+    self
+  }
 }
@@ -1,6 +1,7 @@
+use itertools::Itertools;
 use oxc::index::IndexVec;
 use rolldown_common::{Chunk, ChunkIdx, ModuleIdx};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::type_alias::IndexChunks;
 
@@ -19,4 +20,23 @@ impl ChunkGraph {
   pub fn sorted_chunks(&self) -> impl Iterator<Item = &Chunk> {
     self.sorted_chunk_idx_vec.iter().map(move |&id| &self.chunks[id])
   }
+
+  /// Chunks whose css files need to be loaded before the given chunk is executed, which are the chunk itself and the
+  /// chunks it imports statically. They are sorted in execution order, so styles are applied in import order.
+  pub fn css_chunks_of(&self, chunk_idx: ChunkIdx) -> Vec<ChunkIdx> {
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![chunk_idx];
+    while let Some(idx) = stack.pop() {
+      if visited.insert(idx) {
+        // Unlike `cross_chunk_imports`, this also contains the chunks imported only for side effects.
+        stack
+          .extend(self.chunks[idx].imports_from_other_chunks.iter().map(|(importee, _)| *importee));
+      }
+    }
+    visited
+      .into_iter()
+      .filter(|idx| self.chunks[*idx].has_css)
+      .sorted_by_key(|idx| self.chunks[*idx].exec_order)
+      .collect()
+  }
 }
@@ -118,9 +118,10 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                     let importer_namespace_name =
                       self.canonical_name_for(self.ctx.module.namespace_object_ref);
                     let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
-                    program
-                      .body
-                      .push(self.snippet.import_star_stmt(&importee.name, importee_namespace_name));
+                    program.body.push(self.snippet.import_star_stmt(
+                      &self.external_import_path(importee),
+                      importee_namespace_name,
+                    ));
                     program.body.push(
                       self
                         .snippet
@@ -145,7 +146,9 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                           self.snippet.id_ref_expr(importer_namespace_name, SPAN),
                           self.snippet.call_expr_with_arg_expr_expr(
                             "require",
-                            self.snippet.string_literal_expr(&importee.name, SPAN),
+                            self
+                              .snippet
+                              .string_literal_expr(&self.external_import_path(importee), SPAN),
                           ),
                         )
                         .into_in(self.alloc),
@@ -200,7 +203,15 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
               // `export var foo = 1` => `var foo = 1`
               // `export function foo() {}` => `function foo() {}`
               // `export class Foo {}` => `class Foo {}`
-              top_stmt = ast::Statement::from(decl.take_in(self.alloc));
+              let mut decl = decl.take_in(self.alloc);
+              // The first of the declarations split from `export var a = 1, b = 2` has no span of its own. Give it the
+              // span of the original statement, so its comments are found and it's mapped to the statement.
+              if let ast::Declaration::VariableDeclaration(var_decl) = &mut decl {
+                if var_decl.span.is_unspanned() {
+                  var_decl.span = named_decl.span;
+                }
+              }
+              top_stmt = ast::Statement::from(decl);
             } else {
               // `export { foo }`
               // Remove this statement by ignoring it
@@ -365,6 +376,16 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
   #[allow(clippy::collapsible_else_if, clippy::too_many_lines)]
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    // The default export of an asset module is the file name of the emitted asset, which is relative to the output
+    // directory. Rewrite it to be relative to the chunk.
+    if let Expression::StringLiteral(lit) = expr {
+      let module = self.ctx.module;
+      if matches!(module.module_type, ModuleType::Asset) && lit.value.as_str() == &*module.source {
+        *expr = self.emitted_file_url_expr(&module.source, &self.asset_url_for(&module.source));
+        return;
+      }
+    }
+
     if let Some(call_expr) = expr.as_call_expression_mut() {
       if call_expr.is_global_require_call(self.scope) && !call_expr.span.is_empty() {
         //  `require` calls that can't be recognized by rolldown are ignored in scanning, so they were not stored in `NomralModule#imports`.
@@ -428,7 +449,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
               *request_path = ast::Argument::StringLiteral(
                 self
                   .snippet
-                  .string_literal(&importee.name, request_path.span())
+                  .string_literal(&self.external_import_path(importee), request_path.span())
                   .into_in(self.alloc),
               );
             }
@@ -549,6 +570,17 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     }
 
     walk_mut::walk_expression(self, expr);
+
+    if matches!(self.ctx.options.format, rolldown_common::OutputFormat::Esm) {
+      if let Expression::ImportExpression(import_expr) = expr {
+        let css_files = self.css_files_to_load_for(import_expr);
+        if !css_files.is_empty() {
+          let load_css_fn_name = self.canonical_name_for_runtime("__loadCss");
+          let import_expr = self.snippet.builder.move_expression(expr);
+          *expr = self.snippet.load_css_then_expr(load_css_fn_name, css_files, import_expr);
+        }
+      }
+    }
   }
 
   fn visit_object_property(&mut self, prop: &mut ast::ObjectProperty<'ast>) {
@@ -632,10 +664,21 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
             let import_path = importer_chunk.import_path_for(importee_chunk);
 
-            str.value = self.snippet.atom(&import_path);
+            if self.ctx.options.public_path.applies_to_dynamic_imports() {
+              expr.source = self.emitted_file_url_expr(
+                importee_chunk
+                  .preliminary_filename
+                  .as_ref()
+                  .expect("chunk file name should be generated before finalizing"),
+                &import_path,
+              );
+            } else {
+              str.value = self.snippet.atom(&import_path);
+            }
           }
-          Module::External(_) => {
-            // external module doesn't belong to any chunk, just keep this as it is
+          Module::External(importee) => {
+            // external module doesn't belong to any chunk, only apply `paths` and `make_absolute_externals_relative`
+            str.value = self.snippet.atom(&self.external_import_path(importee));
           }
         }
       }
@@ -645,6 +688,47 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     walk_mut::walk_import_expression(self, expr);
   }
 
+  fn visit_new_expression(&mut self, expr: &mut ast::NewExpression<'ast>) {
+    // Rewrite `new URL('./foo', import.meta.url)` to point to the emitted asset or the chunk of the referenced script.
+    if let Some(rec_id) = self.ctx.module.imports.get(&expr.span).copied() {
+      let importee_id = self.ctx.module.import_records[rec_id].resolved_module;
+      if let (Module::Ecma(importee), Some(url @ ast::Argument::StringLiteral(_))) =
+        (&self.ctx.modules[importee_id], expr.arguments.first_mut())
+      {
+        let chunk_graph = self.ctx.chunk_graph;
+        let new_url = match importee.module_type {
+          ModuleType::Asset => Some(
+            self.emitted_file_url_expr(&importee.source, &self.asset_url_for(&importee.source)),
+          ),
+          // `dataurl` modules and assets below `asset_inline_limit` are inlined
+          ModuleType::Dataurl => Some(self.snippet.string_literal_expr(&importee.source, SPAN)),
+          _ => chunk_graph.entry_module_to_entry_chunk.get(&importee_id).map(|importee_chunk_id| {
+            let importer_chunk_id = chunk_graph.module_to_chunk[self.ctx.module.idx]
+              .expect("Normal module should belong to a chunk");
+            let importee_chunk = &chunk_graph.chunks[*importee_chunk_id];
+            self.emitted_file_url_expr(
+              importee_chunk
+                .preliminary_filename
+                .as_ref()
+                .expect("chunk file name should be generated before finalizing"),
+              &chunk_graph.chunks[importer_chunk_id].import_path_for(importee_chunk),
+            )
+          }),
+        };
+        if let Some(new_url) = new_url {
+          *url = ast::Argument::from(new_url);
+          if let Some(base) = self.import_meta_url_replacement() {
+            if let Some(arg) = expr.arguments.get_mut(1) {
+              *arg = ast::Argument::from(base);
+            }
+          }
+        }
+      }
+    }
+
+    walk_mut::walk_new_expression(self, expr);
+  }
+
   fn visit_assignment_target_property(
     &mut self,
     property: &mut ast::AssignmentTargetProperty<'ast>,
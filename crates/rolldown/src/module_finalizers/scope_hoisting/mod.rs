@@ -1,10 +1,14 @@
 use oxc::{
   allocator::{Allocator, IntoIn},
   ast::ast::{self, IdentifierReference, Statement},
+  parser::Parser,
   semantic::SymbolId,
-  span::{Atom, SPAN},
+  span::{Atom, SourceType, SPAN},
+  syntax::operator::BinaryOperator,
+};
+use rolldown_common::{
+  AstScopes, ExternalModule, ImportRecordIdx, Module, OutputFormat, SymbolRef, WrapKind,
 };
-use rolldown_common::{AstScopes, ImportRecordIdx, Module, SymbolRef, WrapKind};
 use rolldown_ecmascript::{AstSnippet, BindingPatternExt, TakeIn};
 
 mod finalizer_context;
@@ -12,8 +16,9 @@ mod impl_visit_mut;
 pub use finalizer_context::ScopeHoistingFinalizerContext;
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::is_validate_identifier_name;
+use sugar_path::SugarPath;
 
-use crate::types::tree_shake::UsedInfo;
+use crate::{types::tree_shake::UsedInfo, utils::chunk::render_chunk_css::css_filename_of};
 mod rename;
 
 /// Finalizer for emitting output code with scope hoisting.
@@ -44,10 +49,110 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
   }
 
   pub fn canonical_name_for_runtime(&self, name: &str) -> &Rstr {
-    let symbol = self.ctx.runtime.resolve_symbol(name);
+    let symbol = self.ctx.runtime.resolve_symbol(self.ctx.module.idx, name);
     self.canonical_name_for(symbol)
   }
 
+  /// The url of a file emitted to the output directory. It's `relative_url` unless `public_path` is configured.
+  fn emitted_file_url_expr(&self, filename: &str, relative_url: &str) -> ast::Expression<'ast> {
+    let public_path = &self.ctx.options.public_path;
+    if let Some(runtime) = &public_path.runtime {
+      // `window.__CDN__ + "assets/foo.png"`
+      let runtime_expr =
+        Parser::new(self.alloc, self.alloc.alloc_str(runtime), SourceType::default())
+          .parse_expression()
+          .expect("`public_path.runtime` should be validated while normalizing options");
+      return self.snippet.builder.expression_binary(
+        SPAN,
+        runtime_expr,
+        BinaryOperator::Addition,
+        self.snippet.string_literal_expr(filename, SPAN),
+      );
+    }
+    let url = public_path.static_url_for(filename);
+    self.snippet.string_literal_expr(url.as_deref().unwrap_or(relative_url), SPAN)
+  }
+
+  /// `import.meta.url` is a syntax error outside of ES modules, so other formats use the url of the current script.
+  fn import_meta_url_replacement(&self) -> Option<ast::Expression<'ast>> {
+    let source = match self.ctx.options.format {
+      OutputFormat::Esm => return None,
+      OutputFormat::Cjs => "require('url').pathToFileURL(__filename).href",
+      // Workers have no `document`
+      OutputFormat::App | OutputFormat::Iife => {
+        "typeof document === 'undefined' ? self.location.href : document.currentScript && document.currentScript.src || document.baseURI"
+      }
+    };
+    let expr = Parser::new(self.alloc, source, SourceType::default())
+      .parse_expression()
+      .expect("should be a valid expression");
+    Some(expr)
+  }
+
+  /// The url of an emitted asset, relative to the chunk of the module.
+  fn asset_url_for(&self, asset_filename: &str) -> String {
+    let chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+      .expect("Normal module should belong to a chunk");
+    let chunk_filename = self.ctx.chunk_graph.chunks[chunk_id]
+      .absolute_preliminary_filename
+      .as_ref()
+      .expect("chunk file name should be generated before finalizing");
+    let chunk_dir =
+      chunk_filename.as_path().parent().expect("chunk file name should have a parent");
+    let asset_path = asset_filename.as_path().absolutize_with(&self.ctx.options.dir);
+    let url = asset_path.relative(chunk_dir).to_slash_lossy().into_owned();
+    if url.starts_with('.') {
+      url
+    } else {
+      format!("./{url}")
+    }
+  }
+
+  fn external_import_path(&self, importee: &ExternalModule) -> String {
+    let chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+      .expect("Normal module should belong to a chunk");
+    self.ctx.chunk_graph.chunks[chunk_id].import_path_for_external(importee, self.ctx.options)
+  }
+
+  /// Css files that must be loaded before the dynamically imported chunk runs. The ones already required by the
+  /// importer chunk are skipped.
+  fn css_files_to_load_for(
+    &self,
+    import_expr: &ast::ImportExpression,
+  ) -> Vec<ast::Expression<'ast>> {
+    if !matches!(import_expr.source, ast::Expression::StringLiteral(_))
+      || !import_expr.arguments.is_empty()
+    {
+      return vec![];
+    }
+    let rec_id = self.ctx.module.imports[&import_expr.span];
+    let importee_id = self.ctx.module.import_records[rec_id].resolved_module;
+    let Module::Ecma(_) = &self.ctx.modules[importee_id] else {
+      return vec![];
+    };
+    let chunk_graph = self.ctx.chunk_graph;
+    let importer_chunk_id = chunk_graph.module_to_chunk[self.ctx.module.idx]
+      .expect("Normal module should belong to a chunk");
+    let importee_chunk_id = chunk_graph.entry_module_to_entry_chunk[&importee_id];
+    let loaded_css_chunks = chunk_graph.css_chunks_of(importer_chunk_id);
+    chunk_graph
+      .css_chunks_of(importee_chunk_id)
+      .into_iter()
+      .filter(|chunk_id| !loaded_css_chunks.contains(chunk_id))
+      .map(|chunk_id| {
+        let css_chunk = &chunk_graph.chunks[chunk_id];
+        let chunk_filename = css_chunk
+          .preliminary_filename
+          .as_ref()
+          .expect("chunk file name should be generated before finalizing");
+        self.emitted_file_url_expr(
+          &css_filename_of(chunk_filename),
+          &css_filename_of(&chunk_graph.chunks[importer_chunk_id].import_path_for(css_chunk)),
+        )
+      })
+      .collect()
+  }
+
   fn should_remove_import_export_stmt(
     &self,
     stmt: &mut Statement<'ast>,
@@ -1,21 +1,28 @@
 use std::{path::Path, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use futures::future::join_all;
 use oxc::{
   index::IndexVec,
   semantic::{ScopeTree, SymbolTable},
 };
 use rolldown_common::{
+  is_node_builtin,
   side_effects::{DeterminedSideEffects, HookSideEffects},
-  AstScopes, EcmaModule, ImportRecordIdx, ModuleDefFormat, ModuleIdx, ModuleType, PackageJson,
-  RawImportRecord, ResolvedPath, ResolvedRequestInfo, ResourceId, SymbolRef, TreeshakeOptions,
+  AstScopes, CssUrl, EcmaModule, EmittedAsset, ExportsKind, ImportKind, ImportRecordIdx,
+  ModuleDefFormat, ModuleIdx, ModuleType, PackageJson, Platform, RawImportRecord, ResolvedPath,
+  ResolvedRequestInfo, ResourceId, SymbolRef, TreeshakeOptions,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildError;
+use rolldown_fs::FileSystem;
 use rolldown_plugin::{HookResolveIdExtraOptions, SharedPluginDriver};
 use rolldown_resolver::ResolveError;
-use rolldown_utils::{ecma_script::legitimize_identifier_name, path_ext::PathExt};
+use rolldown_utils::{
+  ecma_script::legitimize_identifier_name,
+  path_ext::PathExt,
+  url::{is_data_url, is_http_url, parse_data_url},
+};
 use sugar_path::SugarPath;
 
 use super::{task_context::TaskContext, Msg};
@@ -25,12 +32,29 @@ use crate::{
   runtime::ROLLDOWN_RUNTIME_RESOURCE_ID,
   types::ast_symbols::AstSymbols,
   utils::{
-    load_source::load_source, make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
-    parse_to_ecma_ast::parse_to_ecma_ast, resolve_id::resolve_id,
+    browser_field::find_ignoring_browser_mapping,
+    data_url::{module_type_by_mime, to_data_url},
+    load_source::{forced_asset_inline, load_source, split_query},
+    make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
+    parse_css::{import_specifier, is_remote_url, parse_css, ParsedCss},
+    parse_to_ecma_ast::parse_to_ecma_ast,
+    resolve_id::resolve_id,
     transform_source::transform_source,
+    virtual_modules::resolve_virtual_module,
   },
   SharedOptions, SharedResolver,
 };
+
+/// Files referenced by `url()` in stylesheets are inlined below this size if `asset_inline_limit` isn't set.
+const DEFAULT_CSS_URL_INLINE_LIMIT: usize = 4096;
+
+/// Returns the module type configured for the extension of `path` in `module_types`.
+pub fn module_type_by_extension(path: &str, options: &SharedOptions) -> Option<ModuleType> {
+  let (path, _) = split_query(path);
+  let ext = Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or("js");
+  options.module_types.get(ext).copied()
+}
+
 pub struct EcmaModuleTask {
   ctx: Arc<TaskContext>,
   module_id: ModuleIdx,
@@ -40,9 +64,12 @@ pub struct EcmaModuleTask {
   errors: Vec<BuildError>,
   is_user_defined_entry: bool,
   side_effects: Option<HookSideEffects>,
+  /// Overrides the module type determined by the extension, e.g. files referenced by `new URL(...)` are assets.
+  forced_module_type: Option<ModuleType>,
 }
 
 impl EcmaModuleTask {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     ctx: Arc<TaskContext>,
     id: ModuleIdx,
@@ -51,6 +78,7 @@ impl EcmaModuleTask {
     is_user_defined_entry: bool,
     package_json: Option<Arc<PackageJson>>,
     side_effects: Option<HookSideEffects>,
+    forced_module_type: Option<ModuleType>,
   ) -> Self {
     Self {
       ctx,
@@ -61,6 +89,7 @@ impl EcmaModuleTask {
       is_user_defined_entry,
       package_json,
       side_effects,
+      forced_module_type,
     }
   }
 
@@ -83,22 +112,31 @@ impl EcmaModuleTask {
     let mut hook_side_effects = self.side_effects.take();
     let mut sourcemap_chain = vec![];
     let mut warnings = vec![];
+    let mut errors = vec![];
+    let mut missing_node_builtins = vec![];
 
-    let module_type = {
-      let ext =
-        self.resolved_path.path.as_path().extension().and_then(|ext| ext.to_str()).unwrap_or("js");
-      let module_type = self.ctx.input_options.module_types.get(ext);
-
-      // FIXME: Once we support more types, we should return error instead of defaulting to JS.
-      module_type.copied().unwrap_or(ModuleType::Js)
+    // FIXME: Once we support more types, we should return error instead of defaulting to JS.
+    let module_type = if is_data_url(&self.resolved_path.path) {
+      parse_data_url(&self.resolved_path.path)
+        .map_or(ModuleType::Dataurl, |data_url| module_type_by_mime(&data_url.mime))
+    } else if forced_asset_inline(&self.resolved_path.path).is_some() {
+      ModuleType::Asset
+    } else {
+      let virtual_module = self.ctx.input_options.virtual_modules.get(&*self.resolved_path.path);
+      self.forced_module_type.or(virtual_module.and_then(|m| m.module_type)).unwrap_or_else(|| {
+        module_type_by_extension(&self.resolved_path.path, &self.ctx.input_options)
+          .unwrap_or(ModuleType::Js)
+      })
     };
 
     // Run plugin load to get content first, if it is None using read fs as fallback.
-    let source = load_source(
+    let (source, module_type) = load_source(
       &self.ctx.plugin_driver,
       &self.resolved_path,
       module_type,
       &self.ctx.fs,
+      &self.ctx.file_emitter,
+      &self.ctx.input_options,
       &mut sourcemap_chain,
       &mut hook_side_effects,
     )
@@ -115,19 +153,30 @@ impl EcmaModuleTask {
     .await?
     .into();
 
+    let (css, ecma_source) = if matches!(module_type, ModuleType::Css) {
+      let mut css = parse_css(&source, &self.resolved_path.path, &self.ctx.input_options)?;
+      self.load_css_urls(&mut css, &mut warnings).await?;
+      let facade: Arc<str> = css.facade.as_str().into();
+      (Some(css), facade)
+    } else {
+      (None, Arc::clone(&source))
+    };
+
     let (mut ast, symbols, scopes) = parse_to_ecma_ast(
       &self.ctx.plugin_driver,
       Path::new(&self.resolved_path.path.as_ref()),
       &self.ctx.input_options,
       module_type,
-      Arc::clone(&source),
+      Arc::clone(&ecma_source),
+      &mut errors,
     )?;
 
     let (scope, scan_result, ast_symbol, namespace_object_ref) =
-      self.scan(&mut ast, &source, symbols, scopes);
+      self.scan(&mut ast, &ecma_source, symbols, scopes);
 
-    let resolved_deps =
-      self.resolve_dependencies(&scan_result.import_records, &mut warnings).await?;
+    let resolved_deps = self
+      .resolve_dependencies(&scan_result.import_records, &mut warnings, &mut missing_node_builtins)
+      .await?;
 
     let ScanResult {
       named_imports,
@@ -142,6 +191,10 @@ impl EcmaModuleTask {
       warnings: scan_warnings,
     } = scan_result;
     warnings.extend(scan_warnings);
+    // Ignored modules are empty. They are treated as CommonJS, so named imports from them are `undefined` instead of
+    // missing exports.
+    let exports_kind =
+      if self.resolved_path.ignored { ExportsKind::CommonJs } else { exports_kind };
 
     let mut imported_ids = vec![];
     let mut dynamically_imported_ids = vec![];
@@ -170,7 +223,9 @@ impl EcmaModuleTask {
           p.check_side_effects_for(&stable_resource_id).map(DeterminedSideEffects::UserDefined)
         })
         .unwrap_or_else(|| {
-          let analyzed_side_effects = stmt_infos.iter().any(|stmt_info| stmt_info.side_effect);
+          // Stylesheets are always considered to have side effects, as importing them applies styles.
+          let analyzed_side_effects = matches!(module_type, ModuleType::Css)
+            || stmt_infos.iter().any(|stmt_info| stmt_info.side_effect);
           DeterminedSideEffects::Analyzed(analyzed_side_effects)
         })
     };
@@ -222,6 +277,8 @@ impl EcmaModuleTask {
       dynamically_imported_ids,
       side_effects,
       module_type,
+      package_json: self.package_json.clone(),
+      css_module_idx: None,
     };
 
     self.ctx.plugin_driver.module_parsed(Arc::new(module.to_module_info())).await?;
@@ -233,16 +290,72 @@ impl EcmaModuleTask {
         resolved_deps,
         module_id: self.module_id,
         warnings,
+        errors,
+        missing_node_builtins,
         ast_symbol,
         module,
         raw_import_records: import_records,
         ast,
+        css,
       }))
       .await
       .expect("Send should not fail");
     Ok(())
   }
 
+  /// Load the files referenced by `url()` in the stylesheet. Small files are inlined as data urls, and others are
+  /// emitted as assets.
+  async fn load_css_urls(&self, css: &mut ParsedCss, warnings: &mut Vec<BuildError>) -> Result<()> {
+    for url in std::mem::take(&mut css.url_dependencies) {
+      if url.starts_with('#') || url.starts_with('/') || is_remote_url(&url) {
+        continue;
+      }
+      // The query and the hash are not part of the file path, e.g. `font.woff2?v=1#iefix`.
+      let (path, suffix) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+      let resolved = Self::resolve_id(
+        &self.ctx.input_options,
+        &self.ctx.resolver,
+        &self.ctx.plugin_driver,
+        &self.resolved_path.path,
+        &import_specifier(path),
+        HookResolveIdExtraOptions { is_entry: false, kind: ImportKind::Import },
+      )
+      .await?;
+      let resolved = match resolved {
+        Ok(resolved) if !resolved.is_external => resolved,
+        Ok(_) => continue,
+        Err(e) => {
+          warnings.push(
+            BuildError::unresolved_import_treated_as_external(
+              url.clone(),
+              self.resolved_path.path.to_string(),
+              Some(e),
+            )
+            .with_severity_warning(),
+          );
+          continue;
+        }
+      };
+
+      let file_path = resolved.path.path.as_path();
+      let data = self.ctx.fs.read(file_path).with_context(|| Arc::clone(&resolved.path.path))?;
+      let inline_limit =
+        self.ctx.input_options.asset_inline_limit.unwrap_or(DEFAULT_CSS_URL_INLINE_LIMIT);
+      let css_url = if data.len() < inline_limit {
+        CssUrl::Inline(to_data_url(file_path, data)?)
+      } else {
+        let reference_id = self.ctx.file_emitter.emit_file(EmittedAsset {
+          name: file_path.file_name().map(|name| name.to_string_lossy().into_owned()),
+          file_name: None,
+          source: data.into(),
+        });
+        CssUrl::Asset(format!("{}{suffix}", self.ctx.file_emitter.get_file_name(&reference_id)))
+      };
+      css.urls.insert(url, css_url);
+    }
+    Ok(())
+  }
+
   fn scan(
     &self,
     ast: &mut EcmaAst,
@@ -252,7 +365,12 @@ impl EcmaModuleTask {
   ) -> (AstScopes, ScanResult, AstSymbols, SymbolRef) {
     let (mut ast_symbols, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
     let file_path: ResourceId = Arc::<str>::clone(&self.resolved_path.path).into();
-    let repr_name = file_path.as_path().representative_file_name();
+    // The content of a data url makes a poor name.
+    let repr_name = if is_data_url(&file_path) {
+      "data_url".into()
+    } else {
+      file_path.as_path().representative_file_name()
+    };
     let repr_name = legitimize_identifier_name(&repr_name);
 
     let scanner = AstScanner::new(
@@ -281,7 +399,7 @@ impl EcmaModuleTask {
   ) -> anyhow::Result<Result<ResolvedRequestInfo, ResolveError>> {
     // Check external with unresolved path
     if let Some(is_external) = input_options.external.as_ref() {
-      if is_external(specifier, Some(importer), false).await? {
+      if is_external.call(specifier, Some(importer), false).await? {
         return Ok(Ok(ResolvedRequestInfo {
           path: specifier.to_string().into(),
           module_type: ModuleDefFormat::Unknown,
@@ -303,15 +421,62 @@ impl EcmaModuleTask {
       }));
     }
 
+    // Virtual modules are resolved before plugins and the file system
+    if let Some(resolved_id) = resolve_virtual_module(input_options, specifier, Some(importer)) {
+      return Ok(Ok(resolved_id));
+    }
+    // Other imports in virtual modules are resolved against `cwd`, just like the entries
+    let resolve_importer =
+      (!input_options.virtual_modules.contains_key(importer)).then_some(importer);
+
+    let kind = options.kind;
+    let is_entry = options.is_entry;
     let resolved_id =
-      resolve_id(resolver, plugin_driver, specifier, Some(importer), options).await?;
+      resolve_id(resolver, plugin_driver, specifier, resolve_importer, options).await?;
+
+    // Node.js builtin modules that nothing else resolved are replaced by their polyfills on browser. On node, the
+    // resolver already treats them as external.
+    let resolved_id = match resolved_id {
+      Err(ResolveError::NotFound(_))
+        if matches!(input_options.platform, Platform::Browser) && is_node_builtin(specifier) =>
+      {
+        let name = specifier.strip_prefix("node:").unwrap_or(specifier);
+        match input_options.node_polyfills.get(name) {
+          Some(polyfill) => {
+            let options = HookResolveIdExtraOptions { is_entry, kind };
+            resolve_id(resolver, plugin_driver, polyfill, resolve_importer, options).await?
+          }
+          None => resolved_id,
+        }
+      }
+      _ => resolved_id,
+    };
 
     match resolved_id {
       Ok(mut resolved_id) => {
+        // Bundle `http(s):` imports from their local copies if there are any
+        if resolved_id.is_external && is_http_url(&resolved_id.path.path) {
+          if let Some(local_path) = input_options
+            .http_imports
+            .as_ref()
+            .and_then(|http_imports| http_imports.local_path(&resolved_id.path.path))
+          {
+            if let Ok(local) = resolver.resolve(None, local_path.expect_to_str(), kind).await? {
+              return Ok(Ok(ResolvedRequestInfo {
+                path: local.path,
+                module_type: local.module_type,
+                is_external: false,
+                package_json: local.package_json,
+                side_effects: None,
+              }));
+            }
+          }
+        }
         if !resolved_id.is_external {
           // Check external with resolved path
           if let Some(is_external) = input_options.external.as_ref() {
-            resolved_id.is_external = is_external(specifier, Some(importer), true).await?;
+            resolved_id.is_external =
+              is_external.call_resolved(specifier, &resolved_id.path.path, Some(importer)).await?;
           }
         }
         Ok(Ok(resolved_id))
@@ -324,6 +489,7 @@ impl EcmaModuleTask {
     &mut self,
     dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
     warnings: &mut Vec<BuildError>,
+    missing_node_builtins: &mut Vec<String>,
   ) -> Result<IndexVec<ImportRecordIdx, ResolvedRequestInfo>> {
     let jobs = dependencies.iter_enumerated().map(|(idx, item)| {
       let specifier = item.module_request.clone();
@@ -356,18 +522,38 @@ impl EcmaModuleTask {
 
       match resolved_id {
         Ok(info) => {
+          if info.path.ignored {
+            let importer = Path::new(self.resolved_path.path.as_ref());
+            let browser_mapping = find_ignoring_browser_mapping(
+              &self.ctx.fs,
+              &specifier,
+              importer,
+              Path::new(info.path.path.as_ref()),
+            );
+            warnings.push(
+              BuildError::ignored_module(specifier.to_string(), importer, browser_mapping)
+                .with_severity_warning(),
+            );
+          }
           ret.push(info);
         }
         Err(e) => match &e {
           ResolveError::NotFound(..) => {
-            warnings.push(
-              BuildError::unresolved_import_treated_as_external(
-                specifier.to_string(),
-                self.resolved_path.path.to_string(),
-                Some(e),
-              )
-              .with_severity_warning(),
-            );
+            // The importer chain of builtins is reported after all modules are loaded.
+            if matches!(self.ctx.input_options.platform, Platform::Browser)
+              && is_node_builtin(&specifier)
+            {
+              missing_node_builtins.push(specifier.to_string());
+            } else {
+              warnings.push(
+                BuildError::unresolved_import_treated_as_external(
+                  specifier.to_string(),
+                  self.resolved_path.path.to_string(),
+                  Some(e),
+                )
+                .with_severity_warning(),
+              );
+            }
             ret.push(ResolvedRequestInfo {
               path: specifier.to_string().into(),
               module_type: ModuleDefFormat::Unknown,
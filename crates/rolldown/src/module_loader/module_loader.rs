@@ -1,19 +1,21 @@
 use arcstr::ArcStr;
-use oxc::index::IndexVec;
+use oxc::index::{index_vec, IndexVec};
 use rolldown_common::side_effects::DeterminedSideEffects;
 use rolldown_common::{
-  EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx, ImporterRecord, Module,
-  ModuleIdx, ModuleTable, OutputFormat, ResolvedRequestInfo,
+  CssModule, CssModuleIdx, EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx,
+  ImporterRecord, Module, ModuleIdx, ModuleTable, ModuleType, OutputFormat, ResolvedRequestInfo,
+  SharedFileEmitter,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildError;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
 use std::sync::Arc;
 
-use super::ecma_module_task::EcmaModuleTask;
+use super::ecma_module_task::{module_type_by_extension, EcmaModuleTask};
 use super::runtime_ecma_module_task::RuntimeEcmaModuleTask;
 use super::task_result::NormalModuleTaskResult;
 use super::Msg;
@@ -21,6 +23,7 @@ use crate::module_loader::runtime_ecma_module_task::RuntimeEcmaModuleTaskResult;
 use crate::module_loader::task_context::TaskContext;
 use crate::runtime::{RuntimeModuleBrief, ROLLDOWN_RUNTIME_RESOURCE_ID};
 use crate::types::symbols::Symbols;
+use crate::utils::parse_css::ParsedCss;
 
 use crate::{SharedOptions, SharedResolver};
 
@@ -44,14 +47,29 @@ impl IntermediateNormalModules {
   }
 }
 
+/// Modules of the iife format that are loaded as separate scripts, like workers, can't share chunks with the others.
+/// So their modules are loaded again in isolation, which is identified by the copy of the runtime module they use.
+/// A file that's imported and also referenced as an asset by `new URL(...)` is loaded once for each module type.
+#[derive(PartialEq, Eq, Hash)]
+struct VisitedKey {
+  path: Arc<str>,
+  isolation: Option<ModuleIdx>,
+  forced_module_type: Option<ModuleType>,
+}
+
 pub struct ModuleLoader {
   input_options: SharedOptions,
   shared_context: Arc<TaskContext>,
   rx: tokio::sync::mpsc::Receiver<Msg>,
-  visited: FxHashMap<Arc<str>, ModuleIdx>,
+  visited: FxHashMap<VisitedKey, ModuleIdx>,
   runtime_id: ModuleIdx,
+  /// The copy of the runtime module of each isolated module, including the copies themselves
+  isolated_runtimes: FxHashMap<ModuleIdx, ModuleIdx>,
+  /// The copy of the runtime module used by each isolated entry
+  isolated_entry_runtimes: FxHashMap<Arc<str>, ModuleIdx>,
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
+  css_modules: IndexVec<CssModuleIdx, CssModule>,
   symbols: Symbols,
 }
 
@@ -59,6 +77,7 @@ pub struct ModuleLoaderOutput {
   // Stored all modules
   pub module_table: ModuleTable,
   pub index_ecma_ast: IndexVec<ModuleIdx, EcmaAst>,
+  pub css_modules: IndexVec<CssModuleIdx, CssModule>,
   pub symbols: Symbols,
   // Entries that user defined + dynamic import entries
   pub entry_points: Vec<EntryPoint>,
@@ -71,8 +90,9 @@ impl ModuleLoader {
   pub fn new(
     input_options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
+    file_emitter: SharedFileEmitter,
   ) -> Self {
     // 1024 should be enough for most cases
     // over 1024 pending tasks are insane
@@ -86,35 +106,34 @@ impl ModuleLoader {
       resolver,
       fs,
       plugin_driver,
+      file_emitter,
     });
 
     let mut intermediate_normal_modules = IntermediateNormalModules::new();
     let mut symbols = Symbols::default();
     let runtime_id = intermediate_normal_modules.alloc_ecma_module_idx(&mut symbols);
 
-    let task = RuntimeEcmaModuleTask::new(runtime_id, tx_to_runtime_module);
-
-    #[cfg(target_family = "wasm")]
-    {
-      task.run().unwrap();
-    }
-    // task is sync, but execution time is too short at the moment
-    // so we are using spawn instead of spawn_blocking here to avoid an additional blocking thread creation within tokio
-    #[cfg(not(target_family = "wasm"))]
-    {
-      let handle = tokio::runtime::Handle::current();
-      handle.spawn(async { task.run() });
-    }
+    spawn_runtime_task(runtime_id, tx_to_runtime_module);
 
     Self {
       shared_context: common_data,
       rx,
       input_options,
-      visited: FxHashMap::from_iter([(ROLLDOWN_RUNTIME_RESOURCE_ID.into(), runtime_id)]),
+      visited: FxHashMap::from_iter([(
+        VisitedKey {
+          path: ROLLDOWN_RUNTIME_RESOURCE_ID.into(),
+          isolation: None,
+          forced_module_type: None,
+        },
+        runtime_id,
+      )]),
       runtime_id,
+      isolated_runtimes: FxHashMap::default(),
+      isolated_entry_runtimes: FxHashMap::default(),
       // runtime module is always there
       remaining: 1,
       intermediate_normal_modules,
+      css_modules: IndexVec::new(),
       symbols,
     }
   }
@@ -123,8 +142,14 @@ impl ModuleLoader {
     &mut self,
     info: ResolvedRequestInfo,
     is_user_defined_entry: bool,
+    forced_module_type: Option<ModuleType>,
+    isolation: Option<ModuleIdx>,
   ) -> ModuleIdx {
-    match self.visited.entry(Arc::<str>::clone(&info.path.path)) {
+    // External modules are not bundled, so they don't need to be isolated
+    let isolation = isolation.filter(|_| !info.is_external);
+    let forced_module_type = forced_module_type.filter(|_| !info.is_external);
+    let key = VisitedKey { path: Arc::clone(&info.path.path), isolation, forced_module_type };
+    match self.visited.entry(key) {
       std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
       std::collections::hash_map::Entry::Vacant(not_visited) => {
         if info.is_external {
@@ -147,6 +172,9 @@ impl ModuleLoader {
         } else {
           let id = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
           not_visited.insert(id);
+          if let Some(runtime_id) = isolation {
+            self.isolated_runtimes.insert(id, runtime_id);
+          }
           self.remaining += 1;
           let module_path = info.path.clone();
 
@@ -158,6 +186,7 @@ impl ModuleLoader {
             is_user_defined_entry,
             info.package_json,
             info.side_effects,
+            forced_module_type,
           );
           #[cfg(target_family = "wasm")]
           {
@@ -175,6 +204,27 @@ impl ModuleLoader {
     }
   }
 
+  /// Returns the copy of the runtime module used by the isolated entry at `path`, which is created on the first call.
+  fn isolated_runtime_for(&mut self, path: &Arc<str>) -> ModuleIdx {
+    if let Some(runtime_id) = self.isolated_entry_runtimes.get(path) {
+      return *runtime_id;
+    }
+    let runtime_id = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
+    spawn_runtime_task(runtime_id, self.shared_context.tx.clone());
+    self.remaining += 1;
+    self.isolated_runtimes.insert(runtime_id, runtime_id);
+    self.isolated_entry_runtimes.insert(Arc::clone(path), runtime_id);
+    self.visited.insert(
+      VisitedKey {
+        path: ROLLDOWN_RUNTIME_RESOURCE_ID.into(),
+        isolation: Some(runtime_id),
+        forced_module_type: None,
+      },
+      runtime_id,
+    );
+    runtime_id
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
@@ -186,6 +236,7 @@ impl ModuleLoader {
 
     let mut errors = vec![];
     let mut all_warnings: Vec<BuildError> = vec![];
+    let mut missing_node_builtins = vec![];
 
     let entries_count = user_defined_entries.len() + /* runtime */ 1;
     self.intermediate_normal_modules.modules.reserve(entries_count);
@@ -198,7 +249,7 @@ impl ModuleLoader {
       .into_iter()
       .map(|(name, info)| EntryPoint {
         name,
-        id: self.try_spawn_new_task(info, /* is_user_defined_entry */ true),
+        id: self.try_spawn_new_task(info, /* is_user_defined_entry */ true, None, None),
         kind: EntryPointKind::UserDefined,
       })
       .inspect(|e| {
@@ -207,6 +258,8 @@ impl ModuleLoader {
       .collect::<Vec<_>>();
 
     let mut dynamic_import_entry_ids = FxHashSet::default();
+    // Scripts referenced by `new URL(...)` and workers, which always need their own chunks
+    let mut referenced_entry_ids = FxHashMap::default();
 
     let mut runtime_brief: Option<RuntimeModuleBrief> = None;
 
@@ -223,30 +276,78 @@ impl ModuleLoader {
             mut module,
             raw_import_records,
             warnings,
+            errors: module_errors,
+            missing_node_builtins: builtins,
             ast,
+            css,
           } = task_result;
           all_warnings.extend(warnings);
+          errors.extend(module_errors);
+          missing_node_builtins
+            .extend(builtins.into_iter().map(|specifier| (module_id, specifier)));
 
+          let isolation = self.isolated_runtimes.get(&module_id).copied();
           let import_records = raw_import_records
             .into_iter()
             .zip(resolved_deps)
             .map(|(raw_rec, info)| {
-              let id = self.try_spawn_new_task(info, false);
+              // Scripts referenced by `new URL(...)` are bundled as entries, other files are emitted as assets.
+              let is_new_url_script = matches!(raw_rec.kind, ImportKind::NewUrl)
+                && matches!(
+                  module_type_by_extension(&info.path.path, &self.input_options),
+                  Some(ModuleType::Js | ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx)
+                );
+              let forced_module_type = (matches!(raw_rec.kind, ImportKind::NewUrl)
+                && !is_new_url_script)
+                .then_some(ModuleType::Asset);
+              // Workers and scripts of the iife format can't import chunks, so their modules are isolated
+              let isolation = if matches!(self.input_options.format, OutputFormat::Iife)
+                && (matches!(raw_rec.kind, ImportKind::Worker) || is_new_url_script)
+              {
+                Some(self.isolated_runtime_for(&info.path.path))
+              } else {
+                isolation
+              };
+              let id = self.try_spawn_new_task(info, false, forced_module_type, isolation);
               // Dynamic imported module will be considered as an entry
               self.intermediate_normal_modules.importers[id].push(ImporterRecord {
                 kind: raw_rec.kind,
                 importer_path: module.resource_id.clone(),
               });
-              if matches!(raw_rec.kind, ImportKind::DynamicImport)
-                && !user_defined_entry_ids.contains(&id)
-              {
-                dynamic_import_entry_ids.insert(id);
+              if !user_defined_entry_ids.contains(&id) {
+                match raw_rec.kind {
+                  ImportKind::DynamicImport => {
+                    dynamic_import_entry_ids.insert(id);
+                  }
+                  ImportKind::Worker => {
+                    referenced_entry_ids.insert(id, EntryPointKind::Worker);
+                  }
+                  ImportKind::NewUrl if is_new_url_script => {
+                    referenced_entry_ids.entry(id).or_insert(EntryPointKind::NewUrl);
+                  }
+                  _ => {}
+                }
               }
               raw_rec.into_import_record(id)
             })
             .collect::<IndexVec<ImportRecordIdx, _>>();
           module.import_records = import_records;
 
+          if let Some(ParsedCss { ast, kept_imports, css_modules_pattern, urls, .. }) = css {
+            let idx = self.css_modules.next_idx();
+            self.css_modules.push(CssModule {
+              exec_order: u32::MAX,
+              idx,
+              owner: module_id,
+              kept_imports,
+              urls,
+              ast,
+              source: Arc::clone(&module.source),
+              css_modules_pattern,
+            });
+            module.css_module_idx = Some(idx);
+          }
+
           self.intermediate_normal_modules.modules[module_id] = Some(module.into());
           self.intermediate_normal_modules.index_ecma_ast[module_id] = Some(ast);
 
@@ -254,12 +355,15 @@ impl ModuleLoader {
         }
         Msg::RuntimeNormalModuleDone(task_result) => {
           let RuntimeEcmaModuleTaskResult { ast_symbols, module, runtime, ast } = task_result;
+          let runtime_id = module.idx;
 
-          self.intermediate_normal_modules.modules[self.runtime_id] = Some(module.into());
-          self.intermediate_normal_modules.index_ecma_ast[self.runtime_id] = Some(ast);
+          self.intermediate_normal_modules.modules[runtime_id] = Some(module.into());
+          self.intermediate_normal_modules.index_ecma_ast[runtime_id] = Some(ast);
 
-          self.symbols.add_ast_symbols(self.runtime_id, ast_symbols);
-          runtime_brief = Some(runtime);
+          self.symbols.add_ast_symbols(runtime_id, ast_symbols);
+          if runtime_id == self.runtime_id {
+            runtime_brief = Some(runtime);
+          }
         }
         Msg::BuildErrors(e) => {
           errors.extend(e);
@@ -293,12 +397,27 @@ impl ModuleLoader {
       })
       .collect();
 
+    if !missing_node_builtins.is_empty() {
+      let import_parents = shortest_import_parents(&modules, &entry_points);
+      all_warnings.extend(missing_node_builtins.into_iter().map(|(importer, specifier)| {
+        let mut chain = vec![importer];
+        while let Some(parent) = import_parents[*chain.last().expect("Should not be empty")] {
+          chain.push(parent);
+        }
+        let chain = chain.into_iter().rev().map(|id| modules[id].resource_id().into()).collect();
+        BuildError::missing_node_builtins(specifier, chain).with_severity_warning()
+      }));
+    }
+
     let index_ecma_ast: IndexVec<ModuleIdx, EcmaAst> =
       self.intermediate_normal_modules.index_ecma_ast.into_iter().flatten().collect();
 
     // IIFE format should inline dynamic imports, so here not put dynamic imports to entries
     if !matches!(self.input_options.format, OutputFormat::Iife) {
-      let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
+      let mut dynamic_import_entry_ids = dynamic_import_entry_ids
+        .into_iter()
+        .filter(|id| !referenced_entry_ids.contains_key(id))
+        .collect::<Vec<_>>();
       dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_resource_id());
 
       entry_points.extend(dynamic_import_entry_ids.into_iter().map(|id| EntryPoint {
@@ -308,14 +427,65 @@ impl ModuleLoader {
       }));
     }
 
+    let mut referenced_entry_ids = referenced_entry_ids.into_iter().collect::<Vec<_>>();
+    referenced_entry_ids.sort_unstable_by_key(|(id, _)| modules[*id].stable_resource_id());
+    entry_points.extend(referenced_entry_ids.into_iter().map(|(id, kind)| EntryPoint {
+      name: None,
+      id,
+      kind,
+    }));
+
+    let mut runtime = runtime_brief.expect("Failed to find runtime module. This should not happen");
+    runtime.set_isolated_runtimes(self.isolated_runtimes);
+
     Ok(ModuleLoaderOutput {
       module_table: ModuleTable { modules },
       symbols: self.symbols,
       index_ecma_ast,
+      css_modules: self.css_modules,
       entry_points,
-      runtime: runtime_brief.expect("Failed to find runtime module. This should not happen"),
+      runtime,
       warnings: all_warnings,
       errors,
     })
   }
 }
+
+fn spawn_runtime_task(id: ModuleIdx, tx: tokio::sync::mpsc::Sender<Msg>) {
+  let task = RuntimeEcmaModuleTask::new(id, tx);
+
+  #[cfg(target_family = "wasm")]
+  {
+    task.run().unwrap();
+  }
+  // task is sync, but execution time is too short at the moment
+  // so we are using spawn instead of spawn_blocking here to avoid an additional blocking thread creation within tokio
+  #[cfg(not(target_family = "wasm"))]
+  {
+    let handle = tokio::runtime::Handle::current();
+    handle.spawn(async { task.run() });
+  }
+}
+
+/// Returns the importer of each module on the shortest import path from the entries, so errors can show how a module
+/// is reached. Entries and unreachable modules have no importer.
+fn shortest_import_parents(
+  modules: &IndexVec<ModuleIdx, Module>,
+  entry_points: &[EntryPoint],
+) -> IndexVec<ModuleIdx, Option<ModuleIdx>> {
+  let mut parents = index_vec![None; modules.len()];
+  let mut visited = entry_points.iter().map(|entry| entry.id).collect::<FxHashSet<_>>();
+  let mut queue = entry_points.iter().map(|entry| entry.id).collect::<VecDeque<_>>();
+  while let Some(id) = queue.pop_front() {
+    let Some(module) = modules[id].as_ecma() else {
+      continue;
+    };
+    for rec in &module.import_records {
+      if visited.insert(rec.resolved_module) {
+        parents[rec.resolved_module] = Some(id);
+        queue.push_back(rec.resolved_module);
+      }
+    }
+  }
+  parents
+}
@@ -93,6 +93,8 @@ impl RuntimeEcmaModuleTask {
       dynamically_imported_ids: vec![],
       side_effects: DeterminedSideEffects::Analyzed(false),
       module_type: ModuleType::Js,
+      package_json: None,
+      css_module_idx: None,
     };
 
     if let Err(_err) = self.tx.try_send(Msg::RuntimeNormalModuleDone(RuntimeEcmaModuleTaskResult {
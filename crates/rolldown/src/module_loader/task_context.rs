@@ -1,4 +1,5 @@
-use rolldown_fs::OsFileSystem;
+use rolldown_common::SharedFileEmitter;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 
 use crate::{SharedOptions, SharedResolver};
@@ -10,6 +11,7 @@ pub struct TaskContext {
   pub input_options: SharedOptions,
   pub tx: tokio::sync::mpsc::Sender<Msg>,
   pub resolver: SharedResolver,
-  pub fs: OsFileSystem,
+  pub fs: SharedFileSystem,
   pub plugin_driver: SharedPluginDriver,
+  pub file_emitter: SharedFileEmitter,
 }
@@ -5,7 +5,7 @@ use rolldown_common::{
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildError;
 
-use crate::types::ast_symbols::AstSymbols;
+use crate::{types::ast_symbols::AstSymbols, utils::parse_css::ParsedCss};
 
 pub struct NormalModuleTaskResult {
   pub module_id: ModuleIdx,
@@ -13,6 +13,11 @@ pub struct NormalModuleTaskResult {
   pub resolved_deps: IndexVec<ImportRecordIdx, ResolvedRequestInfo>,
   pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
   pub warnings: Vec<BuildError>,
+  /// Errors that don't stop the module from being scanned, so they are reported along with the errors of other modules.
+  pub errors: Vec<BuildError>,
+  /// Node.js builtin modules imported on the `browser` platform without polyfills.
+  pub missing_node_builtins: Vec<String>,
   pub module: EcmaModule,
   pub ast: EcmaAst,
+  pub css: Option<ParsedCss>,
 }
@@ -6,6 +6,10 @@ use rustc_hash::FxHashMap;
 pub struct RuntimeModuleBrief {
   id: ModuleIdx,
   name_to_symbol: FxHashMap<CompactString, SymbolId>,
+  /// Copies of the runtime module used by modules that are bundled in isolation, like workers of the iife format.
+  /// All copies have the same symbols, since they are parsed from the same source.
+  isolated_runtimes: FxHashMap<ModuleIdx, ModuleIdx>,
+  ids: Vec<ModuleIdx>,
 }
 
 impl RuntimeModuleBrief {
@@ -13,17 +17,43 @@ impl RuntimeModuleBrief {
     Self {
       id,
       name_to_symbol: scope.get_bindings(scope.root_scope_id()).clone().into_iter().collect(),
+      isolated_runtimes: FxHashMap::default(),
+      ids: vec![id],
     }
   }
 
+  /// `isolated_runtimes` maps each isolated module to the copy of the runtime module it uses.
+  pub fn set_isolated_runtimes(&mut self, isolated_runtimes: FxHashMap<ModuleIdx, ModuleIdx>) {
+    self.ids.extend(isolated_runtimes.values().copied().filter(|id| *id != self.id));
+    self.ids.sort_unstable();
+    self.ids.dedup();
+    self.isolated_runtimes = isolated_runtimes;
+  }
+
+  /// The id of the runtime module used by non-isolated modules.
   pub fn id(&self) -> ModuleIdx {
     self.id
   }
 
-  pub fn resolve_symbol(&self, name: &str) -> SymbolRef {
+  /// The ids of the runtime module and its copies.
+  pub fn ids(&self) -> &[ModuleIdx] {
+    &self.ids
+  }
+
+  pub fn is_runtime(&self, id: ModuleIdx) -> bool {
+    self.ids.contains(&id)
+  }
+
+  /// The id of the runtime module used by `importer`.
+  pub fn id_for(&self, importer: ModuleIdx) -> ModuleIdx {
+    self.isolated_runtimes.get(&importer).copied().unwrap_or(self.id)
+  }
+
+  /// Resolves `name` in the runtime module used by `importer`.
+  pub fn resolve_symbol(&self, importer: ModuleIdx, name: &str) -> SymbolRef {
     let symbol_id =
       self.name_to_symbol.get(name).unwrap_or_else(|| panic!("Failed to resolve symbol: {name}"));
-    (self.id, *symbol_id).into()
+    (self.id_for(importer), *symbol_id).into()
   }
 }
 
@@ -55,3 +55,71 @@ export var __toBinary = /* @__PURE__ */ (() => {
     return bytes
   }
 })()
+
+export var __decorateClass = (decorators, target, key, kind) => {
+  var result = kind > 1 ? void 0 : kind ? __getOwnPropDesc(target, key) : target
+  for (var i = decorators.length - 1, decorator; i >= 0; i--)
+    if (decorator = decorators[i])
+      result = (kind ? decorator(target, key, result) : decorator(result)) || result
+  if (kind && result) __defProp(target, key, result)
+  return result
+}
+export var __decorateParam = (index, decorator) => (target, key) => decorator(target, key, index)
+
+var __knownSymbol = (name, symbol) => (symbol = Symbol[name]) ? symbol : Symbol.for('Symbol.' + name)
+var __typeError = msg => { throw TypeError(msg) }
+var __name = (target, value) => __defProp(target, 'name', { value, configurable: true })
+var __decoratorStrings = ['class', 'method', 'getter', 'setter', 'accessor', 'field', 'value', 'get', 'set']
+var __expectFn = fn => fn !== void 0 && typeof fn !== 'function' ? __typeError('Function expected') : fn
+var __decoratorContext = (kind, name, done, metadata, fns) => ({
+  kind: __decoratorStrings[kind],
+  name,
+  metadata,
+  addInitializer: fn => done._ ? __typeError('Already initialized') : fns.push(__expectFn(fn || null)),
+})
+export var __decoratorStart = base => [, , , __create(base?.[__knownSymbol('metadata')] ?? null)]
+export var __decoratorMetadata = (array, target) => __defProp(target, __knownSymbol('metadata'), { value: array[3], configurable: true, writable: true, enumerable: true })
+export var __runInitializers = (array, flags, self, value) => {
+  for (var i = 0, fns = array[flags >> 1], n = fns && fns.length; i < n; i++)
+    flags & 1 ? fns[i].call(self) : value = fns[i].call(self, value)
+  return value
+}
+export var __decorateElement = (array, flags, name, decorators, target) => {
+  var fn, it, done, ctx, access, k = flags & 7, s = !!(flags & 8)
+  var j = k > 3 ? array.length + 1 : k ? s ? 1 : 2 : 0, key = __decoratorStrings[k + 5]
+  var initializers = k > 3 && (array[j - 1] = []), extraInitializers = array[j] || (array[j] = [])
+  var desc = k && (!s && (target = target.prototype), k < 5 && __getOwnPropDesc(target, name))
+  k || __name(target, name)
+
+  for (var i = decorators.length - 1; i >= 0; i--) {
+    ctx = __decoratorContext(k, name, done = {}, array[3], extraInitializers)
+
+    if (k) {
+      ctx.static = s, ctx.private = false, access = ctx.access = { has: x => name in x }
+      if (k ^ 3) access.get = x => x[name]
+      if (k > 2) access.set = (x, y) => x[name] = y
+    }
+
+    it = (0, decorators[i])(k ? k < 4 ? desc[key] : k > 4 ? void 0 : { get: desc.get, set: desc.set } : target, ctx), done._ = 1
+
+    if (k ^ 4 || it === void 0) __expectFn(it) && (k > 4 ? initializers.unshift(it) : k ? desc[key] = it : target = it)
+    else if (typeof it !== 'object' || it === null) __typeError('Object expected')
+    else __expectFn(fn = it.get) && (desc.get = fn), __expectFn(fn = it.set) && (desc.set = fn), __expectFn(fn = it.init) && initializers.unshift(fn)
+  }
+
+  return k || __decoratorMetadata(array, target), desc && __defProp(target, name, desc), target
+}
+
+export var __loadCss = (files, base) => typeof document === 'undefined' ? Promise.resolve() : Promise.all(files.map(file => {
+  var href = new URL(file, base).href
+  for (var link of document.querySelectorAll('link[rel="stylesheet"]'))
+    if (link.href === href) return
+  return new Promise((resolve, reject) => {
+    var link = document.createElement('link')
+    link.rel = 'stylesheet'
+    link.href = href
+    link.onload = resolve
+    link.onerror = () => reject(new Error(`Failed to load css ${href}`))
+    document.head.appendChild(link)
+  })
+}))
@@ -35,9 +35,10 @@ impl<'a> GenerateStage<'a> {
       if let Module::Ecma(importee) = &self.link_output.module_table.modules[rec.resolved_module] {
         // Module imported dynamically will be considered as an entry,
         // so we don't need to include it in this chunk
-        if !matches!(rec.kind, ImportKind::DynamicImport)
+        if rec.kind.is_static()
       // IIFE format should inline dynamic imports
-          || matches!(self.options.format, OutputFormat::Iife)
+          || (matches!(rec.kind, ImportKind::DynamicImport)
+            && matches!(self.options.format, OutputFormat::Iife))
         {
           self.determine_reachable_modules_for_entry(importee.idx, entry_index, module_to_bits);
         }
@@ -153,6 +154,11 @@ impl<'a> GenerateStage<'a> {
       chunk.modules.sort_unstable_by_key(|module_id| {
         self.link_output.module_table.modules[*module_id].exec_order()
       });
+      chunk.has_css = chunk.modules.iter().any(|module_id| {
+        self.link_output.module_table.modules[*module_id]
+          .as_ecma()
+          .is_some_and(|m| m.css_module_idx.is_some())
+      });
     });
 
     chunks
@@ -254,7 +254,8 @@ impl<'a> GenerateStage<'a> {
           if matches!(self.options.format, OutputFormat::Cjs)
             && matches!(entry.exports_kind, ExportsKind::Esm)
           {
-            depended_symbols.insert(self.link_output.runtime.resolve_symbol("__toCommonJS"));
+            depended_symbols
+              .insert(self.link_output.runtime.resolve_symbol(entry.idx, "__toCommonJS"));
             depended_symbols.insert(entry.namespace_object_ref);
           }
         }
@@ -119,6 +119,7 @@ impl<'a> GenerateStage<'a> {
 
     Ok(BundleOutput {
       assets,
+      licenses: vec![],
       warnings: std::mem::take(&mut self.link_output.warnings),
       errors: std::mem::take(&mut self.link_output.errors),
     })
@@ -163,7 +164,7 @@ impl<'a> GenerateStage<'a> {
             // - rollup use the first entered/last executed module as the `[name]` of common chunks.
             // - esbuild always use 'chunk' as the `[name]`. However we try to make the name more meaningful here.
             let first_executed_non_runtime_module =
-              chunk.modules.iter().rev().find(|each| **each != self.link_output.runtime.id());
+              chunk.modules.iter().rev().find(|each| !self.link_output.runtime.is_runtime(**each));
             ChunkNameInfo {
               name: first_executed_non_runtime_module.map_or_else(
                 || arcstr::literal!("chunk"),
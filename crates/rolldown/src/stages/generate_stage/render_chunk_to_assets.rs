@@ -1,6 +1,9 @@
+use std::path::Path;
+
 use futures::future::try_join_all;
-use rolldown_common::{Output, OutputAsset, OutputChunk, SourceMapType};
+use rolldown_common::{LegalComments, Output, OutputAsset, OutputChunk, SourceMapType};
 use rolldown_error::BuildError;
+use rolldown_sourcemap::SourceMap;
 use sugar_path::SugarPath;
 
 use crate::{
@@ -10,6 +13,7 @@ use crate::{
     chunk::{
       finalize_chunks::finalize_chunks,
       render_chunk::{render_chunk, ChunkRenderReturn},
+      render_chunk_css::{css_filename_of, CssRenderReturn},
     },
     render_chunks::render_chunks,
   },
@@ -18,6 +22,7 @@ use crate::{
 use super::GenerateStage;
 
 impl<'a> GenerateStage<'a> {
+  #[allow(clippy::too_many_lines)]
   pub async fn render_chunk_to_assets(
     &mut self,
     chunk_graph: &mut ChunkGraph,
@@ -37,47 +42,46 @@ impl<'a> GenerateStage<'a> {
     let chunks = finalize_chunks(chunk_graph, chunks);
 
     let mut assets = vec![];
-    for ChunkRenderReturn {
-      mut map,
-      rendered_chunk,
-      mut code,
-      file_dir,
-      preliminary_filename,
-      ..
-    } in chunks
+    for (
+      chunk_idx,
+      ChunkRenderReturn {
+        mut map,
+        rendered_chunk,
+        mut code,
+        file_dir,
+        preliminary_filename,
+        legal_comments,
+        css,
+        ..
+      },
+    ) in chunks.into_iter().enumerate()
     {
+      let css_files = chunk_graph
+        .css_chunks_of(chunk_idx.into())
+        .into_iter()
+        .map(|idx| {
+          css_filename_of(
+            chunk_graph.chunks[idx].filename.as_deref().expect("should have file name"),
+          )
+        })
+        .collect();
+
+      if let Some(css) = css {
+        let css_filename = css_filename_of(&rendered_chunk.filename);
+        self.emit_css(css_filename, css, &file_dir, &mut assets).await?;
+      }
+
+      if let Some(legal_asset) =
+        self.emit_legal_comments(&rendered_chunk.filename, &mut code, &legal_comments)
+      {
+        assets.push(legal_asset);
+      }
+
       if let Some(map) = map.as_mut() {
         map.set_file(&rendered_chunk.filename);
 
         let map_filename = format!("{}.map", rendered_chunk.filename.as_str());
-        let map_path = file_dir.join(&map_filename);
-
-        if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
-          let mut x_google_ignore_list = vec![];
-          for (index, source) in map.get_sources().enumerate() {
-            if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
-              #[allow(clippy::cast_possible_truncation)]
-              x_google_ignore_list.push(index as u32);
-            }
-          }
-          if !x_google_ignore_list.is_empty() {
-            map.set_x_google_ignore_list(x_google_ignore_list);
-          }
-        }
-
-        if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
-          let mut sources = Vec::with_capacity(map.get_sources().count());
-          for source in map.get_sources() {
-            sources.push(
-              sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?,
-            );
-          }
-          map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-        }
-
-        // Normalize the windows path at final.
-        let sources = map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
-        map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+        self.post_process_sourcemap(map, &file_dir.join(&map_filename)).await?;
 
         match self.options.sourcemap {
           SourceMapType::File => {
@@ -124,9 +128,101 @@ impl<'a> GenerateStage<'a> {
         map,
         sourcemap_filename,
         preliminary_filename: preliminary_filename.to_string(),
+        css_files,
       })));
     }
 
     Ok(assets)
   }
+
+  /// Create the `.LEGAL.txt` asset for the chunk and link it in the chunk code if needed.
+  fn emit_legal_comments(
+    &self,
+    chunk_filename: &str,
+    code: &mut String,
+    legal_comments: &[String],
+  ) -> Option<Output> {
+    if legal_comments.is_empty() {
+      return None;
+    }
+    let legal_filename = format!("{chunk_filename}.LEGAL.txt");
+    if matches!(self.options.legal_comments, LegalComments::Linked) {
+      let basename = legal_filename.rsplit('/').next().unwrap_or(&legal_filename);
+      code.push_str(&format!("\n/*! For license information please see {basename} */"));
+    }
+    Some(Output::Asset(Box::new(OutputAsset {
+      filename: legal_filename,
+      source: format!("{}\n", legal_comments.join("\n\n")).into(),
+    })))
+  }
+
+  /// Apply the user-defined transforms to the sourcemap and normalize its sources.
+  async fn post_process_sourcemap(
+    &self,
+    map: &mut SourceMap,
+    map_path: &Path,
+  ) -> anyhow::Result<()> {
+    if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
+      let mut x_google_ignore_list = vec![];
+      for (index, source) in map.get_sources().enumerate() {
+        if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
+          #[allow(clippy::cast_possible_truncation)]
+          x_google_ignore_list.push(index as u32);
+        }
+      }
+      if !x_google_ignore_list.is_empty() {
+        map.set_x_google_ignore_list(x_google_ignore_list);
+      }
+    }
+
+    if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
+      let mut sources = Vec::with_capacity(map.get_sources().count());
+      for source in map.get_sources() {
+        sources
+          .push(sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?);
+      }
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    }
+
+    // Normalize the windows path at final.
+    let sources = map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
+    map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    Ok(())
+  }
+
+  /// Create the css asset of the chunk along with its sourcemap.
+  async fn emit_css(
+    &mut self,
+    css_filename: String,
+    css: CssRenderReturn,
+    file_dir: &Path,
+    assets: &mut Vec<Output>,
+  ) -> anyhow::Result<()> {
+    let CssRenderReturn { mut code, map } = css;
+    if let Some(mut map) = map {
+      map.set_file(&css_filename);
+      let map_filename = format!("{css_filename}.map");
+      self.post_process_sourcemap(&mut map, &file_dir.join(&map_filename)).await?;
+      match self.options.sourcemap {
+        SourceMapType::File => match map.to_json_string().map_err(BuildError::sourcemap_error) {
+          Ok(source) => {
+            assets.push(Output::Asset(Box::new(OutputAsset {
+              filename: map_filename.clone(),
+              source: source.into(),
+            })));
+            code.push_str(&format!("\n/*# sourceMappingURL={map_filename} */"));
+          }
+          Err(e) => self.link_output.errors.push(e),
+        },
+        SourceMapType::Inline => match map.to_data_url().map_err(BuildError::sourcemap_error) {
+          Ok(data_url) => code.push_str(&format!("\n/*# sourceMappingURL={data_url} */")),
+          Err(e) => self.link_output.errors.push(e),
+        },
+        SourceMapType::Hidden => {}
+      }
+    }
+    assets
+      .push(Output::Asset(Box::new(OutputAsset { filename: css_filename, source: code.into() })));
+    Ok(())
+  }
 }
@@ -2,8 +2,8 @@ use std::{ptr::addr_of, sync::Mutex};
 
 use oxc::index::IndexVec;
 use rolldown_common::{
-  EntryPoint, ExportsKind, ImportKind, Module, ModuleIdx, ModuleTable, OutputFormat, StmtInfo,
-  SymbolRef, WrapKind,
+  CssModule, CssModuleIdx, EntryPoint, ExportsKind, ImportKind, Module, ModuleIdx, ModuleTable,
+  OutputFormat, StmtInfo, SymbolRef, WrapKind,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildError;
@@ -36,6 +36,7 @@ pub struct LinkStageOutput {
   pub module_table: ModuleTable,
   pub entries: Vec<EntryPoint>,
   pub ast_table: IndexVec<ModuleIdx, EcmaAst>,
+  pub css_modules: IndexVec<CssModuleIdx, CssModule>,
   // pub sorted_modules: Vec<NormalModuleId>,
   pub metas: LinkingMetadataVec,
   pub symbols: Symbols,
@@ -57,6 +58,7 @@ pub struct LinkStage<'a> {
   pub warnings: Vec<BuildError>,
   pub errors: Vec<BuildError>,
   pub ast_table: IndexVec<ModuleIdx, EcmaAst>,
+  pub css_modules: IndexVec<CssModuleIdx, CssModule>,
   pub input_options: &'a SharedOptions,
   pub used_symbol_refs: FxHashSet<SymbolRef>,
   pub top_level_member_expr_resolved_cache: FxHashMap<SymbolRef, MemberChainToResolvedSymbolRef>,
@@ -79,6 +81,7 @@ impl<'a> LinkStage<'a> {
       warnings: scan_stage_output.warnings,
       errors: scan_stage_output.errors,
       ast_table: scan_stage_output.index_ecma_ast,
+      css_modules: scan_stage_output.css_modules,
       input_options,
       used_symbol_refs: FxHashSet::default(),
       top_level_member_expr_resolved_cache: FxHashMap::default(),
@@ -119,7 +122,7 @@ impl<'a> LinkStage<'a> {
         let meta = &self.metas[ecma_module.idx];
         let mut referenced_symbols = vec![];
         if !meta.is_canonical_exports_empty() {
-          referenced_symbols.push(self.runtime.resolve_symbol("__export").into());
+          referenced_symbols.push(self.runtime.resolve_symbol(ecma_module.idx, "__export").into());
         }
         // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
         // Corresponding AST for this statement will be created by the finalizer.
@@ -159,6 +162,7 @@ impl<'a> LinkStage<'a> {
       warnings: self.warnings,
       errors: self.errors,
       ast_table: self.ast_table,
+      css_modules: self.css_modules,
       used_symbol_refs: self.used_symbol_refs,
       top_level_member_expr_resolved_cache: self.top_level_member_expr_resolved_cache,
     }
@@ -254,6 +258,7 @@ impl<'a> LinkStage<'a> {
               }
             }
           }
+          ImportKind::NewUrl | ImportKind::Worker => {}
         }
       });
 
@@ -291,7 +296,7 @@ impl<'a> LinkStage<'a> {
                     {
                       stmt_info
                         .referenced_symbols
-                        .push(self.runtime.resolve_symbol("__toESM").into());
+                        .push(self.runtime.resolve_symbol(importer.idx, "__toESM").into());
                     }
                     let is_reexport_all = importer.star_exports.contains(rec_id);
                     if is_reexport_all {
@@ -301,7 +306,7 @@ impl<'a> LinkStage<'a> {
                       stmt_info.referenced_symbols.push(importer.namespace_object_ref.into());
                       stmt_info
                         .referenced_symbols
-                        .push(self.runtime.resolve_symbol("__reExport").into());
+                        .push(self.runtime.resolve_symbol(importer.idx, "__reExport").into());
                     }
                   }
                   _ => {}
@@ -324,10 +329,10 @@ impl<'a> LinkStage<'a> {
                             .push(importee_linking_info.wrapper_ref.unwrap().into());
                           stmt_info
                             .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                            .push(self.runtime.resolve_symbol(importer.idx, "__toESM").into());
                           stmt_info
                             .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__reExport").into());
+                            .push(self.runtime.resolve_symbol(importer.idx, "__reExport").into());
                           stmt_info.referenced_symbols.push(importer.namespace_object_ref.into());
                         } else {
                           // Turn `import * as bar from 'bar_cjs'` into `var import_bar_cjs = __toESM(require_bar_cjs())`
@@ -339,7 +344,7 @@ impl<'a> LinkStage<'a> {
                           // dbg!(&importee_linking_info.wrapper_ref);
                           stmt_info
                             .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                            .push(self.runtime.resolve_symbol(importer.idx, "__toESM").into());
                           stmt_info.declared_symbols.push(rec.namespace_ref);
                           symbols.lock().unwrap().get_mut(rec.namespace_ref).name =
                             format!("import_{}", &importee.repr_name).into();
@@ -357,7 +362,7 @@ impl<'a> LinkStage<'a> {
                           // something like `__reExport(foo_exports, other_exports)`
                           stmt_info
                             .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__reExport").into());
+                            .push(self.runtime.resolve_symbol(importer.idx, "__reExport").into());
                           stmt_info.referenced_symbols.push(importer.namespace_object_ref.into());
                           stmt_info.referenced_symbols.push(importee.namespace_object_ref.into());
                         }
@@ -381,11 +386,20 @@ impl<'a> LinkStage<'a> {
                         .push(importee_linking_info.wrapper_ref.unwrap().into());
                       stmt_info
                         .referenced_symbols
-                        .push(self.runtime.resolve_symbol("__toCommonJS").into());
+                        .push(self.runtime.resolve_symbol(importer.idx, "__toCommonJS").into());
                       stmt_info.referenced_symbols.push(importee.namespace_object_ref.into());
                     }
                   },
                   ImportKind::DynamicImport => {
+                    // Turn `import('./foo')` into `__loadCss([...], import.meta.url).then(() => import('./foo'))`
+                    if matches!(self.input_options.format, OutputFormat::Esm)
+                      && !self.css_modules.is_empty()
+                      && statically_imports_css(&self.module_table, importee.idx)
+                    {
+                      stmt_info
+                        .referenced_symbols
+                        .push(self.runtime.resolve_symbol(importer.idx, "__loadCss").into());
+                    }
                     if matches!(self.input_options.format, OutputFormat::Iife) {
                       match importee_linking_info.wrap_kind {
                         WrapKind::None => {}
@@ -396,7 +410,7 @@ impl<'a> LinkStage<'a> {
                             .push(importee_linking_info.wrapper_ref.unwrap().into());
                           stmt_info
                             .referenced_symbols
-                            .push(self.runtime.resolve_symbol("__toESM").into());
+                            .push(self.runtime.resolve_symbol(importer.idx, "__toESM").into());
                         }
                         WrapKind::Esm => {
                           // `(init_foo(), foo_exports)`
@@ -408,6 +422,7 @@ impl<'a> LinkStage<'a> {
                       }
                     }
                   }
+                  ImportKind::NewUrl | ImportKind::Worker => {}
                 }
               }
             }
@@ -418,6 +433,29 @@ impl<'a> LinkStage<'a> {
   }
 }
 
+/// Whether the module or any module it imports statically is a stylesheet.
+fn statically_imports_css(module_table: &ModuleTable, module_idx: ModuleIdx) -> bool {
+  let mut visited = FxHashSet::default();
+  let mut stack = vec![module_idx];
+  while let Some(idx) = stack.pop() {
+    let Module::Ecma(module) = &module_table.modules[idx] else { continue };
+    if !visited.insert(idx) {
+      continue;
+    }
+    if module.css_module_idx.is_some() {
+      return true;
+    }
+    stack.extend(
+      module
+        .import_records
+        .iter()
+        .filter(|rec| rec.kind.is_static())
+        .map(|rec| rec.resolved_module),
+    );
+  }
+  false
+}
+
 pub fn init_entry_point_stmt_info(meta: &mut LinkingMetadata) {
   let mut referenced_symbols = vec![];
 
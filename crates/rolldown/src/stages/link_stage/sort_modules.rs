@@ -36,7 +36,7 @@ impl<'a> LinkStage<'a> {
       .iter()
       .rev()
       .map(|entry| Status::ToBeExecuted(entry.id))
-      .chain(iter::once(Status::ToBeExecuted(self.runtime.id())))
+      .chain(self.runtime.ids().iter().rev().map(|id| Status::ToBeExecuted(*id)))
       .collect::<Vec<_>>();
 
     let mut stack_indexes_of_executing_id = FxHashMap::default();
@@ -120,6 +120,11 @@ impl<'a> LinkStage<'a> {
       }
     }
 
+    // Stylesheets are ordered by their facade modules.
+    for css_module in &mut self.css_modules {
+      css_module.exec_order = self.module_table.modules[css_module.owner].exec_order();
+    }
+
     self.sorted_modules = sorted_modules;
     debug_assert_eq!(
       self.sorted_modules.first().copied(),
@@ -21,7 +21,7 @@ struct Context<'a> {
   is_module_included_vec: &'a mut IndexVec<ModuleIdx, bool>,
   used_exports_info_vec: &'a mut IndexVec<ModuleIdx, UsedExportsInfo>,
   tree_shaking: bool,
-  runtime_id: ModuleIdx,
+  runtime_ids: &'a [ModuleIdx],
   metas: &'a LinkingMetadataVec,
   used_symbol_refs: &'a mut FxHashSet<SymbolRef>,
   /// Hash list of string is relatively slow, so we use a two dimensions hashmap to cache the resolved symbol.
@@ -62,7 +62,7 @@ fn include_module(ctx: &mut Context, module: &EcmaModule) {
   }
   ctx.is_module_included_vec[module.idx] = true;
 
-  if module.idx == ctx.runtime_id {
+  if ctx.runtime_ids.contains(&module.idx) {
     // runtime module has no side effects and it's statements should be included
     // by other modules's references.
     return;
@@ -82,6 +82,13 @@ fn include_module(ctx: &mut Context, module: &EcmaModule) {
 
   // Include imported modules for its side effects
   module.import_records.iter().for_each(|import_record| {
+    // `new URL(...)` and workers only reference the file, it isn't executed by the importer
+    if matches!(
+      import_record.kind,
+      rolldown_common::ImportKind::NewUrl | rolldown_common::ImportKind::Worker
+    ) {
+      return;
+    }
     match &ctx.modules[import_record.resolved_module] {
       Module::Ecma(importee) => {
         let bailout_side_effect =
@@ -271,7 +278,7 @@ impl LinkStage<'_> {
       is_included_vec: &mut is_included_vec,
       is_module_included_vec: &mut is_module_included_vec,
       tree_shaking: self.input_options.treeshake.enabled(),
-      runtime_id: self.runtime.id(),
+      runtime_ids: self.runtime.ids(),
       used_exports_info_vec: &mut used_exports_info_vec,
       metas: &self.metas,
       used_symbol_refs: &mut self.used_symbol_refs,
@@ -155,7 +155,7 @@ pub fn create_wrapper(
       let stmt_info = StmtInfo {
         stmt_idx: None,
         declared_symbols: vec![wrapper_ref],
-        referenced_symbols: vec![runtime.resolve_symbol("__commonJSMin").into()],
+        referenced_symbols: vec![runtime.resolve_symbol(module.idx, "__commonJSMin").into()],
         side_effect: false,
         is_included: false,
         import_records: Vec::new(),
@@ -180,7 +180,7 @@ pub fn create_wrapper(
       let stmt_info = StmtInfo {
         stmt_idx: None,
         declared_symbols: vec![wrapper_ref],
-        referenced_symbols: vec![runtime.resolve_symbol("__esmMin").into()],
+        referenced_symbols: vec![runtime.resolve_symbol(module.idx, "__esmMin").into()],
         side_effect: false,
         is_included: false,
         import_records: Vec::new(),
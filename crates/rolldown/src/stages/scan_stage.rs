@@ -4,10 +4,13 @@ use anyhow::Result;
 use arcstr::ArcStr;
 use futures::future::join_all;
 use oxc::index::IndexVec;
-use rolldown_common::{EntryPoint, ImportKind, ModuleIdx, ModuleTable, ResolvedRequestInfo};
+use rolldown_common::{
+  CssModule, CssModuleIdx, EntryPoint, ImportKind, ModuleIdx, ModuleTable, ResolvedRequestInfo,
+  SharedFileEmitter,
+};
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildError;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::{HookResolveIdExtraOptions, SharedPluginDriver};
 use rolldown_resolver::ResolveError;
 
@@ -15,15 +18,16 @@ use crate::{
   module_loader::{module_loader::ModuleLoaderOutput, ModuleLoader},
   runtime::RuntimeModuleBrief,
   types::symbols::Symbols,
-  utils::resolve_id::resolve_id,
+  utils::{resolve_id::resolve_id, virtual_modules::resolve_virtual_module},
   SharedOptions, SharedResolver,
 };
 
 pub struct ScanStage {
   input_options: SharedOptions,
   plugin_driver: SharedPluginDriver,
-  fs: OsFileSystem,
+  fs: SharedFileSystem,
   resolver: SharedResolver,
+  file_emitter: SharedFileEmitter,
   pub errors: Vec<BuildError>,
 }
 
@@ -31,6 +35,7 @@ pub struct ScanStage {
 pub struct ScanStageOutput {
   pub module_table: ModuleTable,
   pub index_ecma_ast: IndexVec<ModuleIdx, EcmaAst>,
+  pub css_modules: IndexVec<CssModuleIdx, CssModule>,
   pub entry_points: Vec<EntryPoint>,
   pub symbols: Symbols,
   pub runtime: RuntimeModuleBrief,
@@ -42,10 +47,11 @@ impl ScanStage {
   pub fn new(
     input_options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
+    file_emitter: SharedFileEmitter,
   ) -> Self {
-    Self { input_options, plugin_driver, fs, resolver, errors: vec![] }
+    Self { input_options, plugin_driver, fs, resolver, file_emitter, errors: vec![] }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -54,11 +60,20 @@ impl ScanStage {
       return Err(anyhow::format_err!("You must supply options.input to rolldown"));
     }
 
+    self.errors.extend(
+      self
+        .resolver
+        .invalid_options()
+        .iter()
+        .map(|(option, explanation)| BuildError::invalid_option(*option, explanation.as_str())),
+    );
+
     let module_loader = ModuleLoader::new(
       Arc::clone(&self.input_options),
       Arc::clone(&self.plugin_driver),
-      self.fs,
+      self.fs.clone(),
       Arc::clone(&self.resolver),
+      Arc::clone(&self.file_emitter),
     );
 
     let user_entries = self.resolve_user_defined_entries().await?;
@@ -71,6 +86,7 @@ impl ScanStage {
       warnings,
       errors,
       index_ecma_ast,
+      css_modules,
     } = module_loader.fetch_all_modules(user_entries).await?;
     self.errors.extend(errors);
 
@@ -81,6 +97,7 @@ impl ScanStage {
       runtime,
       warnings,
       index_ecma_ast,
+      css_modules,
       errors: std::mem::take(&mut self.errors),
     })
   }
@@ -93,20 +110,26 @@ impl ScanStage {
   ) -> Result<Vec<(Option<ArcStr>, ResolvedRequestInfo)>> {
     let resolver = &self.resolver;
     let plugin_driver = &self.plugin_driver;
+    let input_options = &self.input_options;
 
     let resolved_ids = join_all(self.input_options.input.iter().map(|input_item| async move {
       struct Args<'a> {
         specifier: &'a str,
       }
       let args = Args { specifier: &input_item.import };
-      let resolved = resolve_id(
-        resolver,
-        plugin_driver,
-        args.specifier,
-        None,
-        HookResolveIdExtraOptions { is_entry: true, kind: ImportKind::Import },
-      )
-      .await;
+      let resolved = match resolve_virtual_module(input_options, args.specifier, None) {
+        Some(info) => Ok(Ok(info)),
+        None => {
+          resolve_id(
+            resolver,
+            plugin_driver,
+            args.specifier,
+            None,
+            HookResolveIdExtraOptions { is_entry: true, kind: ImportKind::Import },
+          )
+          .await
+        }
+      };
 
       resolved
         .map(|info| (args, info.map(|info| ((input_item.name.clone().map(ArcStr::from)), info))))
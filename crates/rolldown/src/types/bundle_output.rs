@@ -1,4 +1,4 @@
-use rolldown_common::Output;
+use rolldown_common::{Output, ThirdPartyLicense};
 use rolldown_error::BuildError;
 
 #[derive(Default)]
@@ -6,4 +6,6 @@ pub struct BundleOutput {
   pub warnings: Vec<BuildError>,
   pub errors: Vec<BuildError>,
   pub assets: Vec<Output>,
+  /// Third-party packages included in the bundle, sorted by name and version.
+  pub licenses: Vec<ThirdPartyLicense>,
 }
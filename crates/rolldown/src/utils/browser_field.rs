@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+use sugar_path::SugarPath;
+
+/// Finds the entry of a `browser` field that maps `specifier` imported by `importer` to `false`, which made it resolve
+/// to the ignored module at `ignored_path`. Returns the path of the `package.json` and the key of the entry.
+///
+/// The entry is either in the `package.json` of the ignored module, such as `{ "./util.js": false }`, or in the
+/// `package.json` of the importer, such as `{ "fs": false }`.
+pub fn find_ignoring_browser_mapping(
+  fs: &dyn rolldown_fs::FileSystem,
+  specifier: &str,
+  importer: &Path,
+  ignored_path: &Path,
+) -> Option<(PathBuf, String)> {
+  [ignored_path, importer].into_iter().find_map(|path| {
+    let package_json_path = path
+      .ancestors()
+      .skip(1)
+      .map(|dir| dir.join("package.json"))
+      .find(|package_json_path| fs.exists(package_json_path))?;
+    let content = fs.read_to_string(&package_json_path).ok()?;
+    let package_json = serde_json::from_str::<serde_json::Value>(&content).ok()?;
+    let package_dir = package_json_path.parent()?;
+    let key = package_json.get("browser")?.as_object()?.iter().find_map(|(key, value)| {
+      let is_match = value.as_bool() == Some(false)
+        && (key == specifier || package_dir.join(key).normalize() == ignored_path);
+      is_match.then(|| key.clone())
+    })?;
+    Some((package_json_path, key))
+  })
+}
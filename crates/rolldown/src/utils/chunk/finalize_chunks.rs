@@ -54,6 +54,9 @@ pub fn finalize_chunks(
       if let Some(augment_chunk_hash) = &chunk.augment_chunk_hash {
         content.extend(augment_chunk_hash.as_bytes());
       }
+      if let Some(css) = &chunk.css {
+        content.extend(css.code.as_bytes());
+      }
       xxhash_base64_url(&content)
     })
     .collect::<Vec<_>>()
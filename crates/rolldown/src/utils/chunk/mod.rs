@@ -10,6 +10,7 @@ use self::render_chunk_exports::get_chunk_export_names;
 pub mod deconflict_chunk_symbols;
 pub mod finalize_chunks;
 pub mod render_chunk;
+pub mod render_chunk_css;
 pub mod render_chunk_exports;
 pub mod render_chunk_imports;
 
@@ -6,12 +6,14 @@ use crate::{
 };
 
 use anyhow::Result;
+use oxc::span::Span;
 use rolldown_common::{
-  Chunk, ChunkKind, ExportsKind, OutputFormat, RenderedChunk, RenderedModule, ResourceId, WrapKind,
+  Chunk, ChunkKind, ExportsKind, LegalComments, ModuleIdx, OutputFormat, RenderedChunk,
+  RenderedModule, ResourceId, WrapKind,
 };
 use rolldown_sourcemap::{ConcatSource, RawSource, SourceMap};
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
 pub struct ChunkRenderReturn {
@@ -21,10 +23,16 @@ pub struct ChunkRenderReturn {
   pub augment_chunk_hash: Option<String>,
   pub file_dir: PathBuf,
   pub preliminary_filename: ResourceId,
+  /// Legal comments that go to the `.LEGAL.txt` file of the chunk.
+  pub legal_comments: Vec<String>,
+  /// The stylesheets of the chunk, which go to a css file next to the chunk.
+  pub css: Option<CssRenderReturn>,
 }
 
 use super::{
-  generate_rendered_chunk, render_chunk_exports::render_chunk_exports,
+  generate_rendered_chunk,
+  render_chunk_css::{render_chunk_css, CssRenderReturn},
+  render_chunk_exports::render_chunk_exports,
   render_chunk_imports::render_chunk_imports,
 };
 
@@ -38,6 +46,15 @@ pub async fn render_chunk(
   let mut rendered_modules = FxHashMap::default();
   let mut concat_source = ConcatSource::default();
 
+  let legal_comments = collect_legal_comments(this, graph, options);
+  let inline_legal_comments = if matches!(options.legal_comments, LegalComments::Inline) {
+    legal_comments.iter().map(|(idx, comments)| (*idx, comments.as_slice())).collect()
+  } else {
+    FxHashMap::default()
+  };
+  let inline_legal_comments_of =
+    |idx: ModuleIdx| inline_legal_comments.get(&idx).copied().unwrap_or_default();
+
   let rendered_chunk = match options.format {
     OutputFormat::Esm | OutputFormat::Cjs | OutputFormat::Iife => {
       let mut rendered_iter = this
@@ -49,7 +66,13 @@ pub async fn render_chunk(
           (
             m.idx,
             &m.resource_id,
-            render_ecma_module(m, &graph.ast_table[m.idx], m.resource_id.as_ref(), options),
+            render_ecma_module(
+              m,
+              &graph.ast_table[m.idx],
+              m.resource_id.as_ref(),
+              options,
+              inline_legal_comments_of(m.idx),
+            ),
           )
         })
         .collect::<Vec<_>>()
@@ -60,7 +83,7 @@ pub async fn render_chunk(
 
       match maybe_runtime_module {
         Some((id, _, _))
-          if *id == graph.runtime.id() && matches!(options.format, OutputFormat::Cjs) =>
+          if graph.runtime.is_runtime(*id) && matches!(options.format, OutputFormat::Cjs) =>
         {
           let maybe_runtime_module = rendered_iter.next();
           if let Some((_, _module_resource_id, Some(emitted_sources))) = maybe_runtime_module {
@@ -116,8 +139,14 @@ pub async fn render_chunk(
         .copied()
         .filter_map(|id| graph.module_table.modules[id].as_ecma())
         .filter_map(|m| {
-          render_ecma_module(m, &graph.ast_table[m.idx], m.resource_id.as_ref(), options)
-            .map(|rendered| (&m.resource_id, rendered))
+          render_ecma_module(
+            m,
+            &graph.ast_table[m.idx],
+            m.resource_id.as_ref(),
+            options,
+            inline_legal_comments_of(m.idx),
+          )
+          .map(|rendered| (&m.resource_id, rendered))
         })
         .collect::<Vec<_>>()
         .into_iter()
@@ -191,6 +220,19 @@ pub async fn render_chunk(
     concat_source.add_source(Box::new(RawSource::new(exports)));
   }
 
+  let legal_comments =
+    legal_comments.into_iter().flat_map(|(_, comments)| comments).map(|(_, text)| text.to_string());
+  let legal_comments = match options.legal_comments {
+    LegalComments::Eof => {
+      for comment in legal_comments {
+        concat_source.add_source(Box::new(RawSource::new(comment)));
+      }
+      vec![]
+    }
+    LegalComments::Linked | LegalComments::External => legal_comments.collect(),
+    LegalComments::None | LegalComments::Inline => vec![],
+  };
+
   // add footer
   if let Some(footer) = options.footer.as_ref() {
     if let Some(footer_txt) = footer.call(&rendered_chunk).await? {
@@ -221,6 +263,8 @@ pub async fn render_chunk(
     map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
   }
 
+  let css = render_chunk_css(this, graph, options, file_dir)?;
+
   Ok(ChunkRenderReturn {
     code: content,
     map,
@@ -232,5 +276,34 @@ pub async fn render_chunk(
       .as_deref()
       .expect("should have preliminary filename")
       .clone(),
+    legal_comments,
+    css,
   })
 }
+
+/// Collect legal comments of the rendered modules in the chunk. A comment that appears in multiple modules only belongs
+/// to the first one.
+fn collect_legal_comments<'a>(
+  chunk: &Chunk,
+  graph: &'a LinkStageOutput,
+  options: &SharedOptions,
+) -> Vec<(ModuleIdx, Vec<(Span, &'a str)>)> {
+  if options.legal_comments.is_none() {
+    return vec![];
+  }
+  let mut seen = FxHashSet::default();
+  chunk
+    .modules
+    .iter()
+    .filter_map(|id| graph.module_table.modules[*id].as_ecma())
+    .filter_map(|m| {
+      let ast = &graph.ast_table[m.idx];
+      if ast.is_body_empty() {
+        return None;
+      }
+      let comments =
+        ast.legal_comments().into_iter().filter(|(_, text)| seen.insert(*text)).collect::<Vec<_>>();
+      (!comments.is_empty()).then_some((m.idx, comments))
+    })
+    .collect()
+}
@@ -0,0 +1,173 @@
+use std::{borrow::Cow, path::Path};
+
+use anyhow::Result;
+use lightningcss::{
+  dependencies::{Dependency, DependencyOptions},
+  stylesheet::PrinterOptions,
+};
+use rolldown_common::{Chunk, CssModule, CssUrl};
+use rolldown_sourcemap::{lines_count, ConcatSource, RawSource, SourceMap, SourceMapSource};
+use rustc_hash::FxHashSet;
+use sugar_path::SugarPath;
+
+use crate::{stages::link_stage::LinkStageOutput, utils::parse_css::css_targets, SharedOptions};
+
+pub struct CssRenderReturn {
+  pub code: String,
+  pub map: Option<SourceMap>,
+}
+
+/// The css file of a chunk shares the name of the chunk, e.g. `main.js` -> `main.css`.
+pub fn css_filename_of(chunk_filename: &str) -> String {
+  let path = Path::new(chunk_filename);
+  path.with_extension("css").to_slash_lossy().into_owned()
+}
+
+/// Concatenate the stylesheets of the modules in the chunk in execution order. Returns `None` if the chunk contains
+/// no stylesheet.
+pub fn render_chunk_css(
+  chunk: &Chunk,
+  graph: &LinkStageOutput,
+  options: &SharedOptions,
+  file_dir: &Path,
+) -> Result<Option<CssRenderReturn>> {
+  let mut css_modules = chunk
+    .modules
+    .iter()
+    .filter_map(|id| graph.module_table.modules[*id].as_ecma())
+    .filter_map(|m| m.css_module_idx)
+    .map(|idx| &graph.css_modules[idx])
+    .collect::<Vec<_>>();
+  if css_modules.is_empty() {
+    return Ok(None);
+  }
+  css_modules.sort_by_key(|css_module| css_module.exec_order);
+
+  let mut concat_source = ConcatSource::default();
+
+  // `@import` rules must precede all other rules.
+  let mut seen_imports = FxHashSet::default();
+  for kept_import in css_modules.iter().flat_map(|css_module| &css_module.kept_imports) {
+    if seen_imports.insert(kept_import) {
+      concat_source.add_source(Box::new(RawSource::new(kept_import.clone())));
+    }
+  }
+
+  for css_module in css_modules {
+    let (code, map) = print_css_module(css_module, options, file_dir)?;
+    if code.is_empty() {
+      continue;
+    }
+    if let Some(map) = map {
+      let lines = lines_count(&code);
+      concat_source.add_source(Box::new(SourceMapSource::new(code, map, lines)));
+    } else {
+      concat_source.add_source(Box::new(RawSource::new(code)));
+    }
+  }
+
+  let (code, mut map) = concat_source.content_and_sourcemap();
+
+  if let Some(map) = map.as_mut() {
+    let paths =
+      map.get_sources().map(|source| source.as_path().relative(file_dir)).collect::<Vec<_>>();
+    let sources = paths.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
+    map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+  }
+
+  Ok(Some(CssRenderReturn { code, map }))
+}
+
+fn print_css_module(
+  css_module: &CssModule,
+  options: &SharedOptions,
+  file_dir: &Path,
+) -> Result<(String, Option<SourceMap>)> {
+  let project_root = options.cwd.to_string_lossy();
+  let mut parcel_map = (!options.sourcemap.is_hidden()).then(|| {
+    let mut map = parcel_sourcemap::SourceMap::new(&project_root);
+    let source_idx = map.add_source(&css_module.ast.sources[0]);
+    let _ = map.set_source_content(source_idx as usize, &css_module.source);
+    map
+  });
+
+  let printed = css_module
+    .ast
+    .to_css(PrinterOptions {
+      source_map: parcel_map.as_mut(),
+      // Must be the same as the one used in `parse_css`, which affects the hashes of css modules class names.
+      project_root: Some(&project_root),
+      analyze_dependencies: Some(DependencyOptions { remove_imports: false }),
+      minify: options.css_minify,
+      targets: css_targets(options)?,
+      ..PrinterOptions::default()
+    })
+    .map_err(|err| anyhow::format_err!("Failed to print css: {err}"))?;
+  let code = printed.code.trim_end();
+
+  // Urls are printed as quoted placeholders when analyzing dependencies.
+  let mut replacements = vec![];
+  for dep in printed.dependencies.unwrap_or_default() {
+    let Dependency::Url(dep) = dep else { continue };
+    let url = match css_module.urls.get(&dep.url) {
+      Some(CssUrl::Inline(data_url)) => Cow::Borrowed(data_url.as_str()),
+      Some(CssUrl::Asset(file_name)) => {
+        Cow::Owned(options.public_path.static_url_for(file_name).unwrap_or_else(|| {
+          let asset_path = options.cwd.join(&options.dir).join(file_name);
+          asset_path.relative(file_dir).to_slash_lossy().into_owned()
+        }))
+      }
+      None => Cow::Borrowed(dep.url.as_str()),
+    };
+    let escaped = url.replace('\\', "\\\\").replace('"', "\\\"");
+    let placeholder = format!("\"{}\"", dep.placeholder);
+    replacements.extend(
+      code
+        .match_indices(&placeholder)
+        .map(|(start, _)| (start, placeholder.len(), format!("\"{escaped}\""))),
+    );
+  }
+  let code = replace_url_placeholders(code, replacements, parcel_map.as_mut())?;
+
+  let map = match parcel_map {
+    Some(mut parcel_map) => {
+      let json = parcel_map
+        .to_json(None)
+        .map_err(|err| anyhow::format_err!("Failed to generate css sourcemap: {err}"))?;
+      let mut map = SourceMap::from_json_string(&json)?;
+      // Sources of the parcel sourcemap are relative to the project root.
+      let paths = map.get_sources().map(|source| options.cwd.join(source)).collect::<Vec<_>>();
+      let sources = paths.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+      Some(map)
+    }
+    None => None,
+  };
+
+  Ok((code, map))
+}
+
+/// Applies the `(start, len, replacement)` replacements of url placeholders to `code`, and shifts the mappings that
+/// follow each placeholder on its line by the change of length, so the sourcemap still points to the right columns.
+fn replace_url_placeholders(
+  code: &str,
+  mut replacements: Vec<(usize, usize, String)>,
+  mut map: Option<&mut parcel_sourcemap::SourceMap>,
+) -> Result<String> {
+  let mut code = code.to_string();
+  // Replace from the end, so the positions of the remaining placeholders stay valid.
+  replacements.sort_unstable_by_key(|(start, ..)| std::cmp::Reverse(*start));
+  for (start, len, replacement) in replacements {
+    if let Some(map) = map.as_deref_mut() {
+      let line_start = code[..start].rfind('\n').map_or(0, |idx| idx + 1);
+      let line = code[..line_start].matches('\n').count();
+      let end_column = start - line_start + len;
+      #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+      map
+        .offset_columns(line as u32, end_column as u32, replacement.len() as i64 - len as i64)
+        .map_err(|err| anyhow::format_err!("Failed to generate css sourcemap: {err}"))?;
+    }
+    code.replace_range(start..start + len, &replacement);
+  }
+  Ok(code)
+}
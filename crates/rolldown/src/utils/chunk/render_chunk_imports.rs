@@ -104,6 +104,8 @@ pub fn render_chunk_imports(
       .as_external()
       .expect("Should be external module here");
 
+    let importee_module_specifier = chunk.import_path_for_external(importee, options);
+    // External modules are never isolated, so they resolve to the runtime module shared by the chunks
     let external_module_side_effects = &importee.side_effects;
     let mut is_importee_imported = false;
     let mut import_items = named_imports
@@ -117,14 +119,15 @@ pub fn render_chunk_imports(
         match &item.imported {
           Specifier::Star => {
             is_importee_imported = true;
-            let importee_name = &importee.name;
+            let importee_name = &importee_module_specifier;
             match options.format {
               OutputFormat::Esm => {
                 s.push_str(&format!("import * as {alias} from \"{importee_name}\";\n",));
               }
               OutputFormat::Cjs => {
-                let to_esm_fn_name = &chunk.canonical_names
-                  [&graph.symbols.par_canonical_ref_for(graph.runtime.resolve_symbol("__toESM"))];
+                let to_esm_fn_name = &chunk.canonical_names[&graph
+                  .symbols
+                  .par_canonical_ref_for(graph.runtime.resolve_symbol(*importee_id, "__toESM"))];
                 s.push_str(&format!(
                   "const {alias} = {to_esm_fn_name}(require(\"{importee_name}\"));\n",
                 ));
@@ -145,16 +148,15 @@ pub fn render_chunk_imports(
           s.push_str(&format!(
             "import {{ {} }} from \"{importee_module_specifier}\";\n",
             import_items.join(", "),
-            importee_module_specifier = &importee.name
           ));
         }
         OutputFormat::Cjs => {
-          let to_esm_fn_name = &chunk.canonical_names
-            [&graph.symbols.par_canonical_ref_for(graph.runtime.resolve_symbol("__toESM"))];
+          let to_esm_fn_name = &chunk.canonical_names[&graph
+            .symbols
+            .par_canonical_ref_for(graph.runtime.resolve_symbol(*importee_id, "__toESM"))];
           s.push_str(&format!(
             "const {{ {} }} = {to_esm_fn_name}(require(\"{importee_module_specifier}\"));\n",
             import_items.join(", "),
-            importee_module_specifier = &importee.name
           ));
         }
         OutputFormat::App | OutputFormat::Iife => {
@@ -164,7 +166,7 @@ pub fn render_chunk_imports(
     } else if !is_importee_imported {
       // Ensure the side effect
       if external_module_side_effects.has_side_effects() {
-        render_plain_import(&importee.name, &mut s);
+        render_plain_import(&importee_module_specifier, &mut s);
       }
     }
   });
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use rolldown_common::ModuleType;
+use rolldown_utils::mime::guess_mime;
+
+/// Encode the content of the file as a data url. Plain text is percent-encoded, while others are base64-encoded.
+pub fn to_data_url(path: &Path, data: Vec<u8>) -> anyhow::Result<String> {
+  let mime = guess_mime(path, &data)?;
+  let is_plain_text = mime.type_() == mime::TEXT;
+  if is_plain_text {
+    let text = String::from_utf8(data)?;
+    let text = urlencoding::encode(&text);
+    // TODO: should we support non-utf8 text?
+    Ok(format!("data:{mime};charset=utf-8,{text}"))
+  } else {
+    let encoded = rolldown_utils::base64::to_url_safe_base64(&data);
+    Ok(format!("data:{mime};base64,{encoded}"))
+  }
+}
+
+/// The module type of a `data:` import. Unknown media types are kept as data urls.
+pub fn module_type_by_mime(mime: &str) -> ModuleType {
+  match mime {
+    "text/javascript" | "application/javascript" | "text/ecmascript" | "application/ecmascript" => {
+      ModuleType::Js
+    }
+    "text/jsx" => ModuleType::Jsx,
+    "text/typescript" | "application/typescript" => ModuleType::Ts,
+    "text/tsx" => ModuleType::Tsx,
+    "application/json" | "text/json" => ModuleType::Json,
+    "text/css" => ModuleType::Css,
+    "text/plain" => ModuleType::Text,
+    _ => ModuleType::Dataurl,
+  }
+}
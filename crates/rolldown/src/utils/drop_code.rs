@@ -0,0 +1,101 @@
+use oxc::allocator::Vec;
+use oxc::ast::ast::{ChainElement, Expression, Statement};
+use oxc::ast::visit::walk_mut;
+use oxc::ast::VisitMut;
+use oxc::semantic::SymbolTable;
+use oxc::span::GetSpan;
+use rolldown_common::DropOptions;
+use rolldown_ecmascript::{AstSnippet, EcmaAst, WithMutFields};
+
+/// Remove the code specified by the `drop` option.
+///
+/// - `console.*(...)` statements, including optional calls like `console?.log(...)`, are removed. `console.*` calls elsewhere are replaced with `void 0`.
+/// - `debugger` statements are removed.
+/// - `LABEL: ...` statements are removed if `LABEL` is one of the dropped labels.
+///
+/// Since this happens before scanning, the dropped code doesn't count as side effects anymore.
+pub fn drop_code(ast: &mut EcmaAst, options: &DropOptions) {
+  if options.is_empty() {
+    return;
+  }
+  // Resolve references to tell the global `console` from local bindings named `console`.
+  let symbols = options.is_console_dropped().then(|| ast.make_symbol_table_and_scope_tree().0);
+  ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
+    let mut dropper =
+      CodeDropper { snippet: AstSnippet::new(allocator), options, symbols: symbols.as_ref() };
+    dropper.visit_program(program);
+  });
+}
+
+struct CodeDropper<'me, 'ast> {
+  snippet: AstSnippet<'ast>,
+  options: &'me DropOptions,
+  /// Set if `console` calls are dropped.
+  symbols: Option<&'me SymbolTable>,
+}
+
+impl<'me, 'ast> CodeDropper<'me, 'ast> {
+  fn should_drop_stmt(&self, stmt: &Statement<'ast>) -> bool {
+    match stmt {
+      Statement::DebuggerStatement(_) => self.options.is_debugger_dropped(),
+      Statement::LabeledStatement(labeled) => self.options.is_label_dropped(&labeled.label.name),
+      Statement::ExpressionStatement(expr_stmt) => self.is_console_call(&expr_stmt.expression),
+      _ => false,
+    }
+  }
+
+  /// Whether the expression is `console.xxx(...)`, `console.xxx.yyy(...)` or `console[xxx](...)` of the global
+  /// `console`, and `console` calls are dropped. Optional chains like `console?.xxx(...)` and `console.xxx?.(...)`
+  /// are included.
+  fn is_console_call(&self, expr: &Expression) -> bool {
+    let Some(symbols) = self.symbols else {
+      return false;
+    };
+    let call_expr = match expr {
+      Expression::CallExpression(call_expr) => call_expr,
+      Expression::ChainExpression(chain_expr) => match &chain_expr.expression {
+        ChainElement::CallExpression(call_expr) => call_expr,
+        _ => return false,
+      },
+      _ => return false,
+    };
+    let mut callee = &call_expr.callee;
+    loop {
+      callee = match callee {
+        Expression::StaticMemberExpression(member_expr) => &member_expr.object,
+        Expression::ComputedMemberExpression(member_expr) => &member_expr.object,
+        Expression::Identifier(ident) => {
+          // A bare `console(...)` isn't a console API call.
+          return ident.name == "console"
+            && !std::ptr::eq(callee, &call_expr.callee)
+            && ident.reference_id.get().map_or(true, |id| symbols.is_global_reference(id));
+        }
+        _ => return false,
+      };
+    }
+  }
+}
+
+impl<'me, 'ast> VisitMut<'ast> for CodeDropper<'me, 'ast> {
+  fn visit_statements(&mut self, stmts: &mut Vec<'ast, Statement<'ast>>) {
+    stmts.retain(|stmt| !self.should_drop_stmt(stmt));
+    walk_mut::walk_statements(self, stmts);
+  }
+
+  fn visit_statement(&mut self, stmt: &mut Statement<'ast>) {
+    // Statements that are not in a list, such as `if (foo) debugger`.
+    if self.should_drop_stmt(stmt) {
+      *stmt = self.snippet.builder.statement_empty(stmt.span());
+      return;
+    }
+    walk_mut::walk_statement(self, stmt);
+  }
+
+  fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
+    if self.is_console_call(expr) {
+      *expr = self.snippet.void_zero();
+      return;
+    }
+    walk_mut::walk_expression(self, expr);
+  }
+}
@@ -1,54 +1,130 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use anyhow::Context;
-use rolldown_common::{side_effects::HookSideEffects, ModuleType, ResolvedPath};
+use rolldown_common::{
+  side_effects::HookSideEffects, EmittedAsset, FileEmitter, ModuleType, ResolvedPath,
+};
 use rolldown_plugin::{HookLoadArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
-use rolldown_utils::mime::guess_mime;
-use sugar_path::SugarPath;
+use rolldown_utils::url::{is_data_url, parse_data_url};
 
+use super::data_url::to_data_url;
+use crate::SharedOptions;
+
+/// Split a module id like `./logo.svg?url` into the file path and the query.
+pub fn split_query(id: &str) -> (&str, Option<&str>) {
+  match id.split_once('?') {
+    Some((path, query)) => (path, Some(query)),
+    None => (id, None),
+  }
+}
+
+/// Whether the `?inline` or `?url` query forces an asset to be inlined or emitted.
+pub fn forced_asset_inline(id: &str) -> Option<bool> {
+  let (_, query) = split_query(id);
+  query?.split('&').find_map(|param| match param {
+    "inline" => Some(true),
+    "url" => Some(false),
+    _ => None,
+  })
+}
+
+/// Load the source of the module. Assets could be inlined as data urls, so the final module type is returned too.
+#[allow(clippy::too_many_arguments)]
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_path: &ResolvedPath,
   module_type: ModuleType,
   fs: &dyn rolldown_fs::FileSystem,
+  file_emitter: &FileEmitter,
+  options: &SharedOptions,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
-) -> anyhow::Result<String> {
-  let source = if let Some(r) =
-    plugin_driver.load(&HookLoadArgs { id: &resolved_path.path }).await?
-  {
-    if let Some(map) = r.map {
-      sourcemap_chain.push(map);
-    }
-    if let Some(v) = r.side_effects {
-      *side_effects = Some(v);
-    }
-    r.code
-  } else if resolved_path.ignored {
-    String::new()
-  } else {
-    match module_type {
-      ModuleType::Base64 | ModuleType::Binary => {
-        rolldown_utils::base64::to_standard_base64(fs.read(resolved_path.path.as_path())?)
+) -> anyhow::Result<(String, ModuleType)> {
+  let (file_path, _) = split_query(&resolved_path.path);
+  let file_path = Path::new(file_path);
+  let source =
+    if let Some(r) = plugin_driver.load(&HookLoadArgs { id: &resolved_path.path }).await? {
+      if let Some(map) = r.map {
+        sourcemap_chain.push(map);
+      }
+      if let Some(v) = r.side_effects {
+        *side_effects = Some(v);
+      }
+      if matches!(module_type, ModuleType::Asset) {
+        return load_asset(
+          file_emitter,
+          options,
+          resolved_path,
+          module_type,
+          file_path,
+          r.code.into_bytes(),
+        );
+      }
+      r.code
+    } else if let Some(virtual_module) = options.virtual_modules.get(&*resolved_path.path) {
+      match module_type {
+        ModuleType::Base64 | ModuleType::Binary => {
+          rolldown_utils::base64::to_standard_base64(&virtual_module.source)
+        }
+        ModuleType::Dataurl | ModuleType::Asset => {
+          let data = virtual_module.source.clone().into_bytes();
+          return load_asset(file_emitter, options, resolved_path, module_type, file_path, data);
+        }
+        _ => virtual_module.source.clone(),
+      }
+    } else if resolved_path.ignored {
+      String::new()
+    } else if is_data_url(&resolved_path.path) {
+      // Unknown media types are exported as the data url itself.
+      if matches!(module_type, ModuleType::Dataurl) {
+        resolved_path.path.to_string()
+      } else {
+        let data_url = parse_data_url(&resolved_path.path)
+          .with_context(|| format!("Invalid data url: {}", resolved_path.path))?;
+        String::from_utf8(data_url.data)?
       }
-      ModuleType::Dataurl => {
-        let data =
-          fs.read(resolved_path.path.as_path()).with_context(|| Arc::clone(&resolved_path.path))?;
-        let mime = guess_mime(resolved_path.path.as_path(), &data)?;
-        let is_plain_text = mime.type_() == mime::TEXT;
-        if is_plain_text {
-          let text = String::from_utf8(data)?;
-          let text = urlencoding::encode(&text);
-          // TODO: should we support non-utf8 text?
-          format!("data:{mime};charset=utf-8,{text}")
-        } else {
-          let encoded = rolldown_utils::base64::to_url_safe_base64(&data);
-          format!("data:{mime};base64,{encoded}")
+    } else {
+      match module_type {
+        ModuleType::Base64 | ModuleType::Binary => {
+          rolldown_utils::base64::to_standard_base64(fs.read(file_path)?)
+        }
+        ModuleType::Dataurl | ModuleType::Asset => {
+          let data = fs.read(file_path).with_context(|| Arc::clone(&resolved_path.path))?;
+          return load_asset(file_emitter, options, resolved_path, module_type, file_path, data);
         }
+        _ => fs.read_to_string(file_path)?,
       }
-      _ => fs.read_to_string(resolved_path.path.as_path())?,
-    }
-  };
-  Ok(source)
+    };
+  Ok((source, module_type))
+}
+
+/// Assets smaller than `asset_inline_limit` are inlined as data urls, the others are emitted as files. Without the
+/// limit, `dataurl` modules are inlined and `asset` modules are emitted. The `?inline` and `?url` queries override both.
+fn load_asset(
+  file_emitter: &FileEmitter,
+  options: &SharedOptions,
+  resolved_path: &ResolvedPath,
+  module_type: ModuleType,
+  file_path: &Path,
+  data: Vec<u8>,
+) -> anyhow::Result<(String, ModuleType)> {
+  let inline = forced_asset_inline(&resolved_path.path).unwrap_or_else(|| {
+    options
+      .asset_inline_limit
+      .map_or(matches!(module_type, ModuleType::Dataurl), |limit| data.len() < limit)
+  });
+  if inline {
+    Ok((to_data_url(file_path, data)?, ModuleType::Dataurl))
+  } else {
+    Ok((emit_asset(file_emitter, file_path, data), ModuleType::Asset))
+  }
+}
+
+/// Emit the content of an asset module and return the file name of the asset.
+fn emit_asset(file_emitter: &FileEmitter, file_path: &Path, data: Vec<u8>) -> String {
+  let name = file_path.file_name().map(|name| name.to_string_lossy().into_owned());
+  let reference_id =
+    file_emitter.emit_file(EmittedAsset { name, file_name: None, source: data.into() });
+  file_emitter.get_file_name(&reference_id).to_string()
 }
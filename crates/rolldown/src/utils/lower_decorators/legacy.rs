@@ -0,0 +1,109 @@
+use oxc::allocator::{Box, Vec};
+use oxc::ast::ast::{Class, ClassElement, Expression, MethodDefinitionKind, Statement};
+
+use super::{first_decorator_span, DecoratorLowering};
+
+impl<'me, 'ast> DecoratorLowering<'me, 'ast> {
+  /// Lower decorators following TypeScript's `experimentalDecorators`.
+  ///
+  /// ```js
+  /// @dec class Foo { @a method() {} @b static field }
+  /// // to
+  /// class Foo { method() {} static field }
+  /// __decorateClass([a], Foo.prototype, "method", 1)
+  /// __decorateClass([b], Foo, "field", 2)
+  /// Foo = __decorateClass([dec], Foo)
+  /// ```
+  pub(super) fn lower_legacy_class(
+    &mut self,
+    mut class: Box<'ast, Class<'ast>>,
+    class_name: &str,
+    statements: &mut Vec<'ast, Statement<'ast>>,
+  ) {
+    let mut hoisted_names = vec![];
+    let mut instance_decorations = vec![];
+    let mut static_decorations = vec![];
+    let mut ctor_param_decorators = vec![];
+
+    for element in class.body.body.iter_mut() {
+      let (decorations, target) = match element {
+        ClassElement::MethodDefinition(method) => {
+          let span = first_decorator_span(&method.decorators);
+          let mut decorators = self.decorator_exprs(&mut method.decorators);
+          for (index, param) in method.value.params.items.iter_mut().enumerate() {
+            for decorator in self.decorator_exprs(&mut param.decorators) {
+              let index = self.number_expr(u32::try_from(index).unwrap_or_default());
+              decorators.push(self.call_helper("__decorateParam", [index, decorator]));
+            }
+          }
+          if matches!(method.kind, MethodDefinitionKind::Constructor) {
+            ctor_param_decorators = decorators;
+            continue;
+          }
+          if decorators.is_empty() {
+            continue;
+          }
+          let Some(name) = self.element_name_expr(&mut method.key, &mut hoisted_names) else {
+            self.errors.push((span, "Decorators are not valid on private members in legacy mode"));
+            continue;
+          };
+          (decorators, (name, method.r#static, 1))
+        }
+        ClassElement::PropertyDefinition(prop) => {
+          if prop.decorators.is_empty() {
+            continue;
+          }
+          let span = first_decorator_span(&prop.decorators);
+          let decorators = self.decorator_exprs(&mut prop.decorators);
+          let Some(name) = self.element_name_expr(&mut prop.key, &mut hoisted_names) else {
+            self.errors.push((span, "Decorators are not valid on private members in legacy mode"));
+            continue;
+          };
+          (decorators, (name, prop.r#static, 2))
+        }
+        ClassElement::AccessorProperty(prop) => {
+          if !prop.decorators.is_empty() {
+            self.errors.push((
+              first_decorator_span(&prop.decorators),
+              "Decorators on auto-accessors require the `standard` decorators mode",
+            ));
+          }
+          continue;
+        }
+        ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => continue,
+      };
+      let (name, is_static, kind) = target;
+      let call = {
+        let decorators = self.array_expr(decorations);
+        let target = self.member_target_expr(class_name, is_static);
+        let kind = self.number_expr(kind);
+        self.call_helper("__decorateClass", [decorators, target, name, kind])
+      };
+      if is_static {
+        static_decorations.push(call);
+      } else {
+        instance_decorations.push(call);
+      }
+    }
+
+    let mut class_decorators = self.decorator_exprs(&mut class.decorators);
+    class_decorators.extend(ctor_param_decorators);
+
+    if !hoisted_names.is_empty() {
+      statements.push(self.var_decl_without_init(&hoisted_names));
+    }
+    statements.push(Statement::ClassDeclaration(class));
+    statements.extend(
+      instance_decorations
+        .into_iter()
+        .chain(static_decorations)
+        .map(|expr| self.snippet.builder.statement_expression(oxc::span::SPAN, expr)),
+    );
+    if !class_decorators.is_empty() {
+      let decorators = self.array_expr(class_decorators);
+      let target = self.snippet.id_ref_expr(class_name, oxc::span::SPAN);
+      let call: Expression<'ast> = self.call_helper("__decorateClass", [decorators, target]);
+      statements.push(self.assign_stmt(class_name, call));
+    }
+  }
+}
@@ -0,0 +1,445 @@
+mod legacy;
+mod standard;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use oxc::allocator::{Allocator, Box, Vec};
+use oxc::ast::ast::{
+  self, BindingRestElement, Class, ClassElement, Declaration, ExportDefaultDeclarationKind,
+  Expression, ImportOrExportKind, PropertyKey, Statement, TSTypeAnnotation,
+};
+use oxc::ast::visit::{walk, walk_mut};
+use oxc::ast::{Visit, VisitMut};
+use oxc::span::{GetSpan, Span, SPAN};
+use rolldown_common::DecoratorMode;
+use rolldown_ecmascript::{AstSnippet, EcmaAst, TakeIn, WithMutFields};
+use rolldown_error::BuildError;
+use rustc_hash::FxHashSet;
+
+use crate::runtime::ROLLDOWN_RUNTIME_RESOURCE_ID;
+
+/// Lower `@decorator` syntax into plain classes plus calls to the decorator helpers of the runtime module.
+///
+/// Every decorated class is turned into an arrow IIFE that defines the class, applies the decorators and returns the
+/// final class. The IIFE keeps the span of the original class, so a leading `/* @__PURE__ */` annotation still applies.
+///
+/// Decorators that can't be lowered in the given mode are reported as errors of the module at `path`.
+pub fn lower_decorators(
+  ast: &mut EcmaAst,
+  mode: DecoratorMode,
+  path: &Path,
+) -> std::vec::Vec<BuildError> {
+  let mut errors = vec![];
+  ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
+    let mut collector = NameCollector::default();
+    collector.visit_program(program);
+    if !collector.has_decorators {
+      return;
+    }
+
+    let mut lowering = DecoratorLowering {
+      snippet: AstSnippet::new(allocator),
+      alloc: allocator,
+      mode,
+      used_names: collector.names,
+      used_helpers: vec![],
+      errors: vec![],
+    };
+    lowering.visit_program(program);
+
+    if !lowering.used_helpers.is_empty() {
+      program.body.insert(0, lowering.import_helpers_stmt());
+    }
+    errors = lowering.errors;
+  });
+
+  errors
+    .into_iter()
+    .map(|(span, reason)| {
+      BuildError::unsupported_decorator(
+        path.to_string_lossy().into_owned(),
+        Arc::clone(ast.source()),
+        span,
+        reason,
+      )
+    })
+    .collect()
+}
+
+/// Returns `true` if the class itself or any of its elements/parameters is decorated.
+pub fn is_decorated_class(class: &Class) -> bool {
+  !class.decorators.is_empty()
+    || class.body.body.iter().any(|element| match element {
+      ClassElement::MethodDefinition(method) => {
+        !method.decorators.is_empty()
+          || method.value.params.items.iter().any(|param| !param.decorators.is_empty())
+      }
+      ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+      ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+      ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+    })
+}
+
+/// The span of a class including its leading decorators, which is where a `/* @__PURE__ */` annotation is placed.
+pub fn class_span_with_decorators(class: &Class) -> Span {
+  let start = class.decorators.iter().map(|dec| dec.span.start).fold(class.span.start, u32::min);
+  Span::new(start, class.span.end)
+}
+
+#[derive(Default)]
+struct NameCollector {
+  names: FxHashSet<String>,
+  has_decorators: bool,
+}
+
+impl<'a> Visit<'a> for NameCollector {
+  fn visit_decorator(&mut self, decorator: &ast::Decorator<'a>) {
+    self.has_decorators = true;
+    walk::walk_decorator(self, decorator);
+  }
+
+  fn visit_binding_identifier(&mut self, ident: &ast::BindingIdentifier<'a>) {
+    self.names.insert(ident.name.to_string());
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &ast::IdentifierReference<'a>) {
+    self.names.insert(ident.name.to_string());
+  }
+
+  fn visit_private_identifier(&mut self, ident: &ast::PrivateIdentifier<'a>) {
+    self.names.insert(ident.name.to_string());
+  }
+}
+
+pub(super) struct DecoratorLowering<'me, 'ast> {
+  snippet: AstSnippet<'ast>,
+  alloc: &'ast Allocator,
+  mode: DecoratorMode,
+  /// All names that appear in the module. Generated names must not collide with them.
+  used_names: FxHashSet<String>,
+  /// Runtime helpers referenced by the lowered code, in the order of first use.
+  used_helpers: std::vec::Vec<&'me str>,
+  /// Decorators that can't be lowered, with the reason.
+  errors: std::vec::Vec<(Span, &'static str)>,
+}
+
+impl<'me, 'ast> DecoratorLowering<'me, 'ast> {
+  /// Generate a name like `_{base}`, `_{base}2`, ... that doesn't collide with any name in the module.
+  fn generate_name(&mut self, base: &str) -> String {
+    let base = format!("_{base}");
+    let mut name = base.clone();
+    let mut count = 1;
+    while self.used_names.contains(&name) {
+      count += 1;
+      name = format!("{base}{count}");
+    }
+    self.used_names.insert(name.clone());
+    name
+  }
+
+  /// `name(...args)`, where `name` is a helper of the runtime module.
+  fn call_helper(
+    &mut self,
+    name: &'me str,
+    args: impl IntoIterator<Item = Expression<'ast>>,
+  ) -> Expression<'ast> {
+    if !self.used_helpers.contains(&name) {
+      self.used_helpers.push(name);
+    }
+    let mut call_expr = self.snippet.call_expr(name);
+    call_expr.arguments.extend(args.into_iter().map(ast::Argument::from));
+    Expression::CallExpression(self.snippet.builder.alloc(call_expr))
+  }
+
+  /// `import { __decorateClass, ... } from 'rolldown:runtime'`
+  fn import_helpers_stmt(&self) -> Statement<'ast> {
+    let builder = &self.snippet.builder;
+    let specifiers = builder.vec_from_iter(self.used_helpers.iter().map(|name| {
+      ast::ImportDeclarationSpecifier::ImportSpecifier(builder.alloc_import_specifier(
+        SPAN,
+        builder.module_export_name_identifier_name(SPAN, *name),
+        self.snippet.id(name, SPAN),
+        ImportOrExportKind::Value,
+      ))
+    }));
+    Statement::ImportDeclaration(builder.alloc_import_declaration(
+      SPAN,
+      Some(specifiers),
+      self.snippet.string_literal(ROLLDOWN_RUNTIME_RESOURCE_ID, SPAN),
+      None,
+      ImportOrExportKind::Value,
+    ))
+  }
+
+  /// Turn a decorated class into `(() => { class Foo {}; ...; return Foo })()`. `default_name` is the name of the
+  /// class if it's anonymous.
+  fn lower_class(
+    &mut self,
+    mut class: Box<'ast, Class<'ast>>,
+    default_name: &str,
+  ) -> Expression<'ast> {
+    let span = class_span_with_decorators(&class);
+    let (class_name, display_name) = match &class.id {
+      Some(id) => (id.name.to_string(), id.name.to_string()),
+      None => {
+        let name = self.generate_name("class");
+        class.id = Some(self.snippet.id(&name, SPAN));
+        (name, default_name.to_string())
+      }
+    };
+    class.r#type = ast::ClassType::ClassDeclaration;
+    class.span = SPAN;
+
+    let mut statements = self.snippet.builder.vec();
+    if self.mode.is_legacy() {
+      self.lower_legacy_class(class, &class_name, &mut statements);
+    } else {
+      self.lower_standard_class(class, &class_name, &display_name, &mut statements);
+    }
+    statements.push(self.snippet.return_stmt(self.snippet.id_ref_expr(&class_name, SPAN)));
+
+    self.snippet.arrow_iife_expr(span, statements)
+  }
+
+  /// `let [name] = [init]`
+  fn let_decl(&self, name: &str, init: Expression<'ast>) -> Declaration<'ast> {
+    let builder = &self.snippet.builder;
+    builder.declaration_variable(
+      SPAN,
+      ast::VariableDeclarationKind::Let,
+      builder.vec1(builder.variable_declarator(
+        SPAN,
+        ast::VariableDeclarationKind::Let,
+        builder.binding_pattern(
+          builder.binding_pattern_kind_binding_identifier(SPAN, name),
+          None::<Box<'_, TSTypeAnnotation<'_>>>,
+          false,
+        ),
+        Some(init),
+        false,
+      )),
+      false,
+    )
+  }
+
+  /// `var [name1], [name2], ...` without initializers.
+  fn var_decl_without_init(&self, names: &[String]) -> Statement<'ast> {
+    let builder = &self.snippet.builder;
+    Statement::VariableDeclaration(builder.alloc_variable_declaration(
+      SPAN,
+      ast::VariableDeclarationKind::Var,
+      builder.vec_from_iter(names.iter().map(|name| {
+        builder.variable_declarator(
+          SPAN,
+          ast::VariableDeclarationKind::Var,
+          builder.binding_pattern(
+            builder.binding_pattern_kind_binding_identifier(SPAN, name.as_str()),
+            None::<Box<'_, TSTypeAnnotation<'_>>>,
+            false,
+          ),
+          None,
+          false,
+        )
+      })),
+      false,
+    ))
+  }
+
+  /// `[name] = [value];`
+  fn assign_stmt(&self, name: &str, value: Expression<'ast>) -> Statement<'ast> {
+    self.snippet.builder.statement_expression(SPAN, self.assign_expr(name, value))
+  }
+
+  /// `[name] = [value]`
+  fn assign_expr(&self, name: &str, value: Expression<'ast>) -> Expression<'ast> {
+    self.snippet.builder.expression_assignment(
+      SPAN,
+      oxc::syntax::operator::AssignmentOperator::Assign,
+      self.snippet.simple_id_assignment_target(name, SPAN),
+      value,
+    )
+  }
+
+  /// `[a, b, ...]`
+  fn array_expr(&self, elements: impl IntoIterator<Item = Expression<'ast>>) -> Expression<'ast> {
+    let builder = &self.snippet.builder;
+    builder.expression_array(
+      SPAN,
+      builder.vec_from_iter(elements.into_iter().map(ast::ArrayExpressionElement::from)),
+      None,
+    )
+  }
+
+  fn decorator_exprs(
+    &self,
+    decorators: &mut Vec<'ast, ast::Decorator<'ast>>,
+  ) -> std::vec::Vec<Expression<'ast>> {
+    decorators.take_in(self.alloc).into_iter().map(|dec| dec.expression).collect()
+  }
+
+  /// Returns an expression that evaluates to the name of a class element. Computed keys are evaluated only once by
+  /// rewriting `[expr]` to `[_key = expr]` and referring to `_key` afterwards.
+  fn element_name_expr(
+    &mut self,
+    key: &mut PropertyKey<'ast>,
+    hoisted_names: &mut std::vec::Vec<String>,
+  ) -> Option<Expression<'ast>> {
+    match key {
+      PropertyKey::StaticIdentifier(ident) => {
+        Some(self.snippet.string_literal_expr(ident.name.as_str(), SPAN))
+      }
+      PropertyKey::StringLiteral(lit) => Some(self.snippet.string_literal_expr(&lit.value, SPAN)),
+      PropertyKey::NumericLiteral(lit) => {
+        Some(self.snippet.string_literal_expr(&lit.value.to_string(), SPAN))
+      }
+      PropertyKey::PrivateIdentifier(_) => None,
+      _ => {
+        let name = self.generate_name("key");
+        let expr = key.to_expression_mut().take_in(self.alloc);
+        *key = PropertyKey::from(self.assign_expr(&name, expr));
+        let ret = self.snippet.id_ref_expr(&name, SPAN);
+        hoisted_names.push(name);
+        Some(ret)
+      }
+    }
+  }
+
+  /// `Foo.prototype` or `Foo`
+  fn member_target_expr(&self, class_name: &str, is_static: bool) -> Expression<'ast> {
+    if is_static {
+      self.snippet.id_ref_expr(class_name, SPAN)
+    } else {
+      self.snippet.literal_prop_access_member_expr_expr(class_name, "prototype")
+    }
+  }
+
+  fn number_expr(&self, value: u32) -> Expression<'ast> {
+    let raw = self.snippet.atom(&value.to_string());
+    self.snippet.number_expr(f64::from(value), raw.as_str())
+  }
+
+  fn lower_class_decl_stmt(&mut self, stmt: Statement<'ast>, out: &mut Vec<'ast, Statement<'ast>>) {
+    let builder = self.snippet.builder;
+    match stmt {
+      Statement::ClassDeclaration(class) if is_decorated_class(&class) => {
+        let name = class.id.as_ref().map(|id| id.name.to_string()).unwrap_or_default();
+        let expr = self.lower_class(class, "");
+        out.push(Statement::from(self.let_decl(&name, expr)));
+      }
+      Statement::ExportNamedDeclaration(mut decl) if matches!(&decl.declaration, Some(Declaration::ClassDeclaration(class)) if is_decorated_class(class)) =>
+      {
+        let Some(Declaration::ClassDeclaration(class)) = decl.declaration.take() else {
+          unreachable!()
+        };
+        let name = class.id.as_ref().map(|id| id.name.to_string()).unwrap_or_default();
+        let expr = self.lower_class(class, "");
+        decl.declaration = Some(self.let_decl(&name, expr));
+        out.push(Statement::ExportNamedDeclaration(decl));
+      }
+      Statement::ExportDefaultDeclaration(mut decl) if matches!(&decl.declaration, ExportDefaultDeclarationKind::ClassDeclaration(class) if is_decorated_class(class)) =>
+      {
+        let ExportDefaultDeclarationKind::ClassDeclaration(class) = std::mem::replace(
+          &mut decl.declaration,
+          ExportDefaultDeclarationKind::from(<Expression as TakeIn>::dummy(self.alloc)),
+        ) else {
+          unreachable!()
+        };
+        match class.id.as_ref().map(|id| id.name.to_string()) {
+          Some(name) => {
+            // `export default @dec class Foo {}` => `let Foo = ...; export { Foo as default }`
+            let expr = self.lower_class(class, "");
+            out.push(Statement::from(self.let_decl(&name, expr)));
+            out.push(Statement::ExportNamedDeclaration(builder.alloc_export_named_declaration(
+              decl.span,
+              None,
+              builder.vec1(builder.export_specifier(
+                SPAN,
+                builder.module_export_name_identifier_reference(SPAN, name.as_str()),
+                builder.module_export_name_identifier_name(SPAN, "default"),
+                ImportOrExportKind::Value,
+              )),
+              None,
+              ImportOrExportKind::Value,
+              None,
+            )));
+          }
+          None => {
+            decl.declaration =
+              ExportDefaultDeclarationKind::from(self.lower_class(class, "default"));
+            out.push(Statement::ExportDefaultDeclaration(decl));
+          }
+        }
+      }
+      stmt => out.push(stmt),
+    }
+  }
+}
+
+impl<'me, 'ast> VisitMut<'ast> for DecoratorLowering<'me, 'ast> {
+  fn visit_statements(&mut self, stmts: &mut Vec<'ast, Statement<'ast>>) {
+    // Nested classes are lowered first.
+    walk_mut::walk_statements(self, stmts);
+
+    let needs_lowering = stmts.iter().any(|stmt| match stmt {
+      Statement::ClassDeclaration(class) => is_decorated_class(class),
+      Statement::ExportNamedDeclaration(decl) => {
+        matches!(&decl.declaration, Some(Declaration::ClassDeclaration(class)) if is_decorated_class(class))
+      }
+      Statement::ExportDefaultDeclaration(decl) => {
+        matches!(&decl.declaration, ExportDefaultDeclarationKind::ClassDeclaration(class) if is_decorated_class(class))
+      }
+      _ => false,
+    });
+    if !needs_lowering {
+      return;
+    }
+
+    let original = stmts.take_in(self.alloc);
+    stmts.reserve(original.len());
+    for stmt in original {
+      self.lower_class_decl_stmt(stmt, stmts);
+    }
+  }
+
+  fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
+    walk_mut::walk_expression(self, expr);
+
+    if matches!(expr, Expression::ClassExpression(class) if is_decorated_class(class)) {
+      let Expression::ClassExpression(class) = expr.take_in(self.alloc) else { unreachable!() };
+      *expr = self.lower_class(class, "");
+    }
+  }
+}
+
+/// `function ([params]) { [statements] }`
+fn function_expr<'ast>(
+  snippet: &AstSnippet<'ast>,
+  params: Vec<'ast, ast::FormalParameter<'ast>>,
+  statements: Vec<'ast, Statement<'ast>>,
+) -> Box<'ast, ast::Function<'ast>> {
+  let builder = snippet.builder;
+  builder.alloc_function(
+    ast::FunctionType::FunctionExpression,
+    SPAN,
+    None,
+    false,
+    false,
+    false,
+    None::<Box<'_, ast::TSTypeParameterDeclaration<'_>>>,
+    None,
+    builder.formal_parameters(
+      SPAN,
+      ast::FormalParameterKind::UniqueFormalParameters,
+      params,
+      None::<Box<'_, BindingRestElement<'_>>>,
+    ),
+    Some(builder.function_body(SPAN, builder.vec(), statements)),
+    None::<Box<'_, TSTypeAnnotation<'_>>>,
+  )
+}
+
+/// Spans of the decorators of a class element, used for error messages.
+fn first_decorator_span(decorators: &[ast::Decorator]) -> Span {
+  decorators.first().map_or(SPAN, GetSpan::span)
+}
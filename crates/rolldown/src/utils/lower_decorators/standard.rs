@@ -0,0 +1,524 @@
+use oxc::allocator::{Box, Vec};
+use oxc::ast::ast::{
+  self, Class, ClassElement, Expression, MethodDefinitionKind, PropertyKey, Statement,
+  TSTypeAnnotation,
+};
+use oxc::span::SPAN;
+use rolldown_ecmascript::TakeIn;
+use rustc_hash::FxHashMap;
+
+use super::{first_decorator_span, function_expr, DecoratorLowering};
+
+/// A decorated class element that needs a `__decorateElement(...)` call.
+struct Decoration<'ast> {
+  /// Decorators are applied to static methods, instance methods, static fields and instance fields in that order.
+  group: u8,
+  flags: u32,
+  name: Expression<'ast>,
+  decorators_name: String,
+}
+
+const KIND_METHOD: u32 = 1;
+const KIND_GETTER: u32 = 2;
+const KIND_SETTER: u32 = 3;
+const KIND_ACCESSOR: u32 = 4;
+const KIND_FIELD: u32 = 5;
+const FLAG_STATIC: u32 = 8;
+
+fn decoration_group(is_static: bool, is_field: bool) -> u8 {
+  match (is_field, is_static) {
+    (false, true) => 0,
+    (false, false) => 1,
+    (true, true) => 2,
+    (true, false) => 3,
+  }
+}
+
+impl<'me, 'ast> DecoratorLowering<'me, 'ast> {
+  /// Lower decorators following the TC39 proposal (2023-11).
+  ///
+  /// ```js
+  /// @dec class Foo { @a method() {} @b field = 1 }
+  /// // to
+  /// var _init, _Foo_dec, _method_dec, _field_dec;
+  /// _Foo_dec = [dec];
+  /// _init = __decoratorStart(void 0);
+  /// _method_dec = [a];
+  /// _field_dec = [b];
+  /// class Foo {
+  ///   static {
+  ///     __decorateElement(_init, 1, "method", _method_dec, this);
+  ///     __decorateElement(_init, 5, "field", _field_dec, this);
+  ///   }
+  ///   #_init = __runInitializers(_init, 5, this);
+  ///   method() {}
+  ///   field = __runInitializers(_init, 8, this, 1);
+  ///   #_extra = __runInitializers(_init, 11, this);
+  /// }
+  /// Foo = __decorateElement(_init, 0, "Foo", _Foo_dec, Foo);
+  /// __runInitializers(_init, 1, Foo);
+  /// ```
+  #[allow(clippy::too_many_lines)]
+  pub(super) fn lower_standard_class(
+    &mut self,
+    mut class: Box<'ast, Class<'ast>>,
+    class_name: &str,
+    display_name: &str,
+    statements: &mut Vec<'ast, Statement<'ast>>,
+  ) {
+    let builder = self.snippet.builder;
+    let init_name = self.generate_name("init");
+    let mut var_names = vec![init_name.clone()];
+    let mut assignments = vec![];
+
+    let class_decorators = self.decorator_exprs(&mut class.decorators);
+    let class_decorators_name = (!class_decorators.is_empty()).then(|| {
+      let name = self.generate_name(&format!("{class_name}_dec"));
+      assignments.push(self.assign_stmt(&name, self.array_expr(class_decorators)));
+      var_names.push(name.clone());
+      name
+    });
+
+    let base = match &mut class.super_class {
+      None => self.snippet.void_zero(),
+      Some(Expression::Identifier(ident)) => self.snippet.id_ref_expr(&ident.name, SPAN),
+      Some(super_class) => {
+        let name = self.generate_name("super");
+        let super_class = std::mem::replace(super_class, self.snippet.id_ref_expr(&name, SPAN));
+        assignments.push(self.assign_stmt(&name, super_class));
+        let base = self.snippet.id_ref_expr(&name, SPAN);
+        var_names.push(name);
+        base
+      }
+    };
+    let start = self.call_helper("__decoratorStart", [base]);
+    assignments.push(self.assign_stmt(&init_name, start));
+
+    // Field-like elements store their initializers in the slot that `__decorateElement` allocates for them, so the
+    // slots are numbered in the order the decorators are applied.
+    let mut field_like = class
+      .body
+      .body
+      .iter()
+      .enumerate()
+      .filter_map(|(idx, element)| match element {
+        ClassElement::PropertyDefinition(prop) if !prop.decorators.is_empty() => {
+          Some((decoration_group(prop.r#static, true), idx))
+        }
+        ClassElement::AccessorProperty(prop) if !prop.decorators.is_empty() => {
+          Some((decoration_group(prop.r#static, false), idx))
+        }
+        _ => None,
+      })
+      .collect::<std::vec::Vec<_>>();
+    field_like.sort_by_key(|(group, _)| *group);
+    let slots = field_like
+      .into_iter()
+      .enumerate()
+      .map(|(slot, (_, idx))| (idx, u32::try_from(slot).unwrap_or_default()))
+      .collect::<FxHashMap<_, _>>();
+
+    let mut decorations = vec![];
+    let mut hoisted_key_names = vec![];
+    let mut pending_instance_extras = vec![];
+    let mut has_static_method_decorators = false;
+    let mut has_instance_method_decorators = false;
+    let mut elements = builder.vec();
+
+    for (idx, element) in class.body.body.take_in(self.alloc).into_iter().enumerate() {
+      match element {
+        ClassElement::MethodDefinition(mut method) => {
+          if let Some(param_decorator) =
+            method.value.params.items.iter().find_map(|param| param.decorators.first())
+          {
+            self.errors.push((
+              param_decorator.span,
+              "Parameter decorators require the `legacy` decorators mode",
+            ));
+          }
+          if method.decorators.is_empty()
+            || matches!(method.kind, MethodDefinitionKind::Constructor)
+          {
+            elements.push(ClassElement::MethodDefinition(method));
+            continue;
+          }
+          let span = first_decorator_span(&method.decorators);
+          let decorators_name = self.element_decorators_name(&method.key);
+          let decorators = self.decorator_exprs(&mut method.decorators);
+          let Some(name) = self.element_name_expr(&mut method.key, &mut hoisted_key_names) else {
+            self.errors.push((span, "Decorating private members is not supported"));
+            elements.push(ClassElement::MethodDefinition(method));
+            continue;
+          };
+          assignments.push(self.assign_stmt(&decorators_name, self.array_expr(decorators)));
+          let kind = match method.kind {
+            MethodDefinitionKind::Get => KIND_GETTER,
+            MethodDefinitionKind::Set => KIND_SETTER,
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => KIND_METHOD,
+          };
+          if method.r#static {
+            has_static_method_decorators = true;
+          } else {
+            has_instance_method_decorators = true;
+          }
+          decorations.push(Decoration {
+            group: decoration_group(method.r#static, false),
+            flags: kind | if method.r#static { FLAG_STATIC } else { 0 },
+            name,
+            decorators_name,
+          });
+          elements.push(ClassElement::MethodDefinition(method));
+        }
+        ClassElement::PropertyDefinition(mut prop) => {
+          if prop.decorators.is_empty() {
+            if !prop.r#static && !pending_instance_extras.is_empty() {
+              let value = prop.value.take().unwrap_or_else(|| self.snippet.void_zero());
+              prop.value = Some(self.with_pending_extras(&mut pending_instance_extras, value));
+            }
+            elements.push(ClassElement::PropertyDefinition(prop));
+            continue;
+          }
+          let span = first_decorator_span(&prop.decorators);
+          let decorators_name = self.element_decorators_name(&prop.key);
+          let decorators = self.decorator_exprs(&mut prop.decorators);
+          let Some(name) = self.element_name_expr(&mut prop.key, &mut hoisted_key_names) else {
+            self.errors.push((span, "Decorating private members is not supported"));
+            elements.push(ClassElement::PropertyDefinition(prop));
+            continue;
+          };
+          assignments.push(self.assign_stmt(&decorators_name, self.array_expr(decorators)));
+          let slot = slots[&idx];
+          let value = self.run_field_initializers(&init_name, slot, prop.value.take());
+          prop.value = Some(if prop.r#static {
+            value
+          } else {
+            self.with_pending_extras(&mut pending_instance_extras, value)
+          });
+          decorations.push(Decoration {
+            group: decoration_group(prop.r#static, true),
+            flags: KIND_FIELD | if prop.r#static { FLAG_STATIC } else { 0 },
+            name,
+            decorators_name,
+          });
+          let is_static = prop.r#static;
+          elements.push(ClassElement::PropertyDefinition(prop));
+          self.push_field_extras(
+            &init_name,
+            slot,
+            is_static,
+            &mut elements,
+            &mut pending_instance_extras,
+          );
+        }
+        ClassElement::AccessorProperty(mut prop) => {
+          if prop.decorators.is_empty() {
+            elements.push(ClassElement::AccessorProperty(prop));
+            continue;
+          }
+          let span = first_decorator_span(&prop.decorators);
+          let decorators_name = self.element_decorators_name(&prop.key);
+          let storage_name = match &prop.key {
+            PropertyKey::StaticIdentifier(ident) => self.generate_name(&ident.name),
+            _ => self.generate_name("accessor"),
+          };
+          let decorators = self.decorator_exprs(&mut prop.decorators);
+          let Some(name) = self.element_name_expr(&mut prop.key, &mut hoisted_key_names) else {
+            self.errors.push((span, "Decorating private members is not supported"));
+            elements.push(ClassElement::AccessorProperty(prop));
+            continue;
+          };
+          assignments.push(self.assign_stmt(&decorators_name, self.array_expr(decorators)));
+          let slot = slots[&idx];
+          let is_static = prop.r#static;
+
+          // `accessor x = 1` => `#_x = 1; get x() { return this.#_x } set x(v) { this.#_x = v }`
+          let value = self.run_field_initializers(&init_name, slot, prop.value.take());
+          let value = if is_static {
+            value
+          } else {
+            self.with_pending_extras(&mut pending_instance_extras, value)
+          };
+          elements.push(self.property_element(
+            builder.property_key_private_identifier(SPAN, storage_name.as_str()),
+            Some(value),
+            false,
+            is_static,
+          ));
+          let placeholder = self.clone_key(&prop.key, &name);
+          let getter_key = std::mem::replace(&mut prop.key, placeholder);
+          let setter_key = self.clone_key(&prop.key, &name);
+          elements.push(self.accessor_element(
+            getter_key,
+            prop.computed,
+            is_static,
+            &storage_name,
+            MethodDefinitionKind::Get,
+          ));
+          elements.push(self.accessor_element(
+            setter_key,
+            prop.computed,
+            is_static,
+            &storage_name,
+            MethodDefinitionKind::Set,
+          ));
+          decorations.push(Decoration {
+            group: decoration_group(is_static, false),
+            flags: KIND_ACCESSOR | if is_static { FLAG_STATIC } else { 0 },
+            name,
+            decorators_name,
+          });
+          self.push_field_extras(
+            &init_name,
+            slot,
+            is_static,
+            &mut elements,
+            &mut pending_instance_extras,
+          );
+        }
+        element @ (ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_)) => {
+          elements.push(element);
+        }
+      }
+    }
+
+    if !pending_instance_extras.is_empty() {
+      let extra_name = self.generate_name("extra");
+      let value = self.with_pending_extras(&mut pending_instance_extras, self.snippet.void_zero());
+      elements.push(self.property_element(
+        builder.property_key_private_identifier(SPAN, extra_name.as_str()),
+        Some(value),
+        false,
+        false,
+      ));
+    }
+
+    let mut prologue = vec![];
+    if !decorations.is_empty() {
+      // `static { __decorateElement(_init, 1, "method", _method_dec, this); ... }`
+      decorations.sort_by_key(|decoration| decoration.group);
+      let mut block = builder.vec();
+      for decoration in decorations {
+        var_names.push(decoration.decorators_name.clone());
+        let args = [
+          self.snippet.id_ref_expr(&init_name, SPAN),
+          self.number_expr(decoration.flags),
+          decoration.name,
+          self.snippet.id_ref_expr(&decoration.decorators_name, SPAN),
+          builder.expression_this(SPAN),
+        ];
+        let call = self.call_helper("__decorateElement", args);
+        block.push(builder.statement_expression(SPAN, call));
+      }
+      if class_decorators_name.is_none() {
+        let args = [self.snippet.id_ref_expr(&init_name, SPAN), builder.expression_this(SPAN)];
+        let call = self.call_helper("__decoratorMetadata", args);
+        block.push(builder.statement_expression(SPAN, call));
+      }
+      if has_static_method_decorators {
+        let call = self.run_initializers_call(&init_name, 3, builder.expression_this(SPAN));
+        block.push(builder.statement_expression(SPAN, call));
+      }
+      prologue.push(builder.class_element_static_block(SPAN, block));
+    }
+    if has_instance_method_decorators {
+      // `#_init = __runInitializers(_init, 5, this)` runs before any other instance field.
+      let call = self.run_initializers_call(&init_name, 5, builder.expression_this(SPAN));
+      prologue.push(self.property_element(
+        builder.property_key_private_identifier(SPAN, init_name.as_str()),
+        Some(call),
+        false,
+        false,
+      ));
+    }
+    class.body.body = builder.vec_from_iter(prologue.into_iter().chain(elements));
+
+    var_names.extend(hoisted_key_names);
+    statements.push(self.var_decl_without_init(&var_names));
+    statements.extend(assignments);
+    statements.push(Statement::ClassDeclaration(class));
+
+    if let Some(class_decorators_name) = class_decorators_name {
+      let args = [
+        self.snippet.id_ref_expr(&init_name, SPAN),
+        self.number_expr(0),
+        self.snippet.string_literal_expr(display_name, SPAN),
+        self.snippet.id_ref_expr(&class_decorators_name, SPAN),
+        self.snippet.id_ref_expr(class_name, SPAN),
+      ];
+      let call = self.call_helper("__decorateElement", args);
+      statements.push(self.assign_stmt(class_name, call));
+      let call =
+        self.run_initializers_call(&init_name, 1, self.snippet.id_ref_expr(class_name, SPAN));
+      statements.push(builder.statement_expression(SPAN, call));
+    }
+  }
+
+  /// Name of the variable holding the evaluated decorators of a class element, like `_method_dec`.
+  fn element_decorators_name(&mut self, key: &PropertyKey<'ast>) -> String {
+    match key {
+      PropertyKey::StaticIdentifier(ident) => self.generate_name(&format!("{}_dec", ident.name)),
+      _ => self.generate_name("dec"),
+    }
+  }
+
+  /// `__runInitializers([init_name], [flags], [target])`
+  fn run_initializers_call(
+    &mut self,
+    init_name: &str,
+    flags: u32,
+    target: Expression<'ast>,
+  ) -> Expression<'ast> {
+    let args = [self.snippet.id_ref_expr(init_name, SPAN), self.number_expr(flags), target];
+    self.call_helper("__runInitializers", args)
+  }
+
+  /// `__runInitializers(_init, 8 + 4 * slot, this, value)`
+  fn run_field_initializers(
+    &mut self,
+    init_name: &str,
+    slot: u32,
+    value: Option<Expression<'ast>>,
+  ) -> Expression<'ast> {
+    let mut call = self.run_initializers_call(
+      init_name,
+      8 + 4 * slot,
+      self.snippet.builder.expression_this(SPAN),
+    );
+    if let (Some(value), Expression::CallExpression(call)) = (value, &mut call) {
+      call.arguments.push(ast::Argument::from(value));
+    }
+    call
+  }
+
+  /// Extra initializers of a field run right after the field is defined. For static fields, that's a static block
+  /// following the field. For instance fields, they are prepended to the initializer of the next instance field.
+  fn push_field_extras(
+    &mut self,
+    init_name: &str,
+    slot: u32,
+    is_static: bool,
+    elements: &mut Vec<'ast, ClassElement<'ast>>,
+    pending_instance_extras: &mut std::vec::Vec<Expression<'ast>>,
+  ) {
+    let builder = self.snippet.builder;
+    let call = self.run_initializers_call(init_name, 11 + 4 * slot, builder.expression_this(SPAN));
+    if is_static {
+      elements.push(
+        builder
+          .class_element_static_block(SPAN, builder.vec1(builder.statement_expression(SPAN, call))),
+      );
+    } else {
+      pending_instance_extras.push(call);
+    }
+  }
+
+  /// `(extra1, extra2, value)`
+  fn with_pending_extras(
+    &self,
+    pending_extras: &mut std::vec::Vec<Expression<'ast>>,
+    value: Expression<'ast>,
+  ) -> Expression<'ast> {
+    if pending_extras.is_empty() {
+      return value;
+    }
+    let builder = self.snippet.builder;
+    let expressions = builder.vec_from_iter(pending_extras.drain(..).chain(std::iter::once(value)));
+    builder.expression_parenthesized(SPAN, builder.expression_sequence(SPAN, expressions))
+  }
+
+  fn property_element(
+    &self,
+    key: PropertyKey<'ast>,
+    value: Option<Expression<'ast>>,
+    computed: bool,
+    is_static: bool,
+  ) -> ClassElement<'ast> {
+    self.snippet.builder.class_element_property_definition(
+      ast::PropertyDefinitionType::PropertyDefinition,
+      SPAN,
+      self.snippet.builder.vec(),
+      key,
+      value,
+      computed,
+      is_static,
+      false,
+      false,
+      false,
+      false,
+      false,
+      None::<Box<'_, TSTypeAnnotation<'_>>>,
+      None,
+    )
+  }
+
+  /// `get [key]() { return this.#storage }` or `set [key](_) { this.#storage = _ }`
+  fn accessor_element(
+    &self,
+    key: PropertyKey<'ast>,
+    computed: bool,
+    is_static: bool,
+    storage_name: &str,
+    kind: MethodDefinitionKind,
+  ) -> ClassElement<'ast> {
+    let builder = self.snippet.builder;
+    let storage = builder.member_expression_private_field_expression(
+      SPAN,
+      builder.expression_this(SPAN),
+      builder.private_identifier(SPAN, storage_name),
+      false,
+    );
+    let (params, body) = if matches!(kind, MethodDefinitionKind::Set) {
+      let param = builder.formal_parameter(
+        SPAN,
+        builder.vec(),
+        builder.binding_pattern(
+          builder.binding_pattern_kind_binding_identifier(SPAN, "_"),
+          None::<Box<'_, TSTypeAnnotation<'_>>>,
+          false,
+        ),
+        None,
+        false,
+        false,
+      );
+      let assign = builder.expression_assignment(
+        SPAN,
+        oxc::syntax::operator::AssignmentOperator::Assign,
+        ast::AssignmentTarget::from(builder.simple_assignment_target_member_expression(storage)),
+        self.snippet.id_ref_expr("_", SPAN),
+      );
+      (builder.vec1(param), builder.statement_expression(SPAN, assign))
+    } else {
+      (builder.vec(), self.snippet.return_stmt(builder.expression_member(storage)))
+    };
+    builder.class_element_method_definition(
+      ast::MethodDefinitionType::MethodDefinition,
+      SPAN,
+      builder.vec(),
+      key,
+      function_expr(&self.snippet, params, builder.vec1(body)),
+      kind,
+      computed,
+      is_static,
+      false,
+      false,
+      None,
+    )
+  }
+
+  /// Make a copy of a class element key. `name` is the expression returned by `element_name_expr` for `key`.
+  fn clone_key(&self, key: &PropertyKey<'ast>, name: &Expression<'ast>) -> PropertyKey<'ast> {
+    let builder = self.snippet.builder;
+    match (key, name) {
+      (PropertyKey::StaticIdentifier(ident), _) => {
+        builder.property_key_identifier_name(SPAN, ident.name.clone())
+      }
+      (_, Expression::Identifier(ident)) => {
+        PropertyKey::from(self.snippet.id_ref_expr(&ident.name, SPAN))
+      }
+      (_, Expression::StringLiteral(lit)) => {
+        PropertyKey::from(self.snippet.string_literal_expr(&lit.value, SPAN))
+      }
+      _ => unreachable!("element_name_expr only returns identifiers or string literals"),
+    }
+  }
+}
@@ -6,22 +6,30 @@ use super::module_finalizers::scope_hoisting::{
   ScopeHoistingFinalizer, ScopeHoistingFinalizerContext,
 };
 pub mod augment_chunk_hash;
+pub mod browser_field;
 pub mod call_expression_ext;
 pub mod chunk;
+pub mod data_url;
+pub mod drop_code;
 pub mod extract_hash_pattern;
 pub mod extract_meaningful_input_name_from_path;
 pub mod hash_placeholder;
 pub mod load_source;
+pub mod lower_decorators;
 pub mod make_ast_symbol_and_scope;
+pub mod new_expression_ext;
 pub mod normalize_options;
+pub mod parse_css;
 pub mod parse_to_ecma_ast;
 pub mod pre_process_ecma_ast;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
 pub mod resolve_id;
+pub mod third_party_licenses;
 pub mod transform_source;
 pub mod tweak_ast_for_scanning;
+pub mod virtual_modules;
 
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn finalize_normal_module(
@@ -0,0 +1,52 @@
+use oxc::ast::ast;
+use rolldown_common::AstScopes;
+
+use super::parse_css::is_remote_url;
+
+pub trait NewExpressionExt<'ast> {
+  /// Returns the url of `new URL('./foo', import.meta.url)`, which refers to a file relative to the current module.
+  fn new_url_with_import_meta(&self, scope: &AstScopes) -> Option<&ast::StringLiteral<'ast>>;
+
+  /// Returns the `new URL(...)` expression and its url of `new Worker(new URL('./foo', import.meta.url))` or
+  /// `new SharedWorker(...)`.
+  fn new_worker_with_url(
+    &self,
+    scope: &AstScopes,
+  ) -> Option<(&ast::NewExpression<'ast>, &ast::StringLiteral<'ast>)>;
+}
+
+impl<'ast> NewExpressionExt<'ast> for ast::NewExpression<'ast> {
+  fn new_url_with_import_meta(&self, scope: &AstScopes) -> Option<&ast::StringLiteral<'ast>> {
+    if !is_global_callee(&self.callee, &["URL"], scope) {
+      return None;
+    }
+    let [ast::Argument::StringLiteral(url), ast::Argument::StaticMemberExpression(base)] =
+      self.arguments.as_slice()
+    else {
+      return None;
+    };
+    let is_import_meta_url = base.property.name == "url"
+      && matches!(&base.object, ast::Expression::MetaProperty(meta) if meta.meta.name == "import" && meta.property.name == "meta");
+    (is_import_meta_url && !is_remote_url(&url.value)).then_some(&**url)
+  }
+
+  fn new_worker_with_url(
+    &self,
+    scope: &AstScopes,
+  ) -> Option<(&ast::NewExpression<'ast>, &ast::StringLiteral<'ast>)> {
+    if !is_global_callee(&self.callee, &["Worker", "SharedWorker"], scope) {
+      return None;
+    }
+    let Some(ast::Argument::NewExpression(new_url)) = self.arguments.first() else { return None };
+    new_url.new_url_with_import_meta(scope).map(|url| (&**new_url, url))
+  }
+}
+
+fn is_global_callee(callee: &ast::Expression, names: &[&str], scope: &AstScopes) -> bool {
+  match callee {
+    ast::Expression::Identifier(ident) if names.contains(&ident.name.as_str()) => {
+      ident.reference_id.get().map_or(true, |ref_id| scope.is_unresolved(ref_id))
+    }
+    _ => false,
+  }
+}
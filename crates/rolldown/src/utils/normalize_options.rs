@@ -1,12 +1,21 @@
-use rolldown_common::{ModuleType, NormalizedBundlerOptions, Platform, SourceMapType};
+use oxc::{allocator::Allocator, parser::Parser, span::SourceType};
+use rolldown_common::{
+  HttpImports, ModuleType, NormalizedBundlerOptions, Platform, PublicPathOptions, SourceMapType,
+};
+use rolldown_fs::FileSystem;
 use rustc_hash::FxHashMap;
 
 pub struct NormalizeOptionsReturn {
   pub options: NormalizedBundlerOptions,
   pub resolve_options: rolldown_resolver::ResolveOptions,
+  /// The name and the reason of each invalid option. Invalid values are replaced by their defaults.
+  pub invalid_options: Vec<(&'static str, String)>,
 }
 
-pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOptionsReturn {
+pub fn normalize_options(
+  mut raw_options: crate::BundlerOptions,
+  fs: &dyn FileSystem,
+) -> NormalizeOptionsReturn {
   // Take out resolve options
 
   let raw_resolve = std::mem::take(&mut raw_options.resolve).unwrap_or_default();
@@ -23,6 +32,7 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       ("tsx".to_string(), ModuleType::Tsx),
       ("json".to_string(), ModuleType::Json),
       ("txt".to_string(), ModuleType::Text),
+      ("css".to_string(), ModuleType::Css),
     ]
     .into_iter()
     .collect(),
@@ -44,14 +54,40 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
 
   loaders.extend(user_defined_loaders);
 
+  let cwd =
+    raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+
+  let external = raw_options.external.map(|mut external| {
+    external.load_dependencies(&cwd, fs);
+    external
+  });
+
+  let mut invalid_options = vec![];
+
+  let public_path =
+    normalize_public_path(raw_options.public_path.unwrap_or_default(), &mut invalid_options);
+
+  let http_imports = raw_options.http_imports.map(|http_imports| match http_imports {
+    HttpImports::CacheDir(dir) => HttpImports::CacheDir(cwd.join(dir)),
+    HttpImports::Fn(_) => http_imports,
+  });
+
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
-    external: raw_options.external,
+    cwd,
+    external,
+    make_absolute_externals_relative: raw_options.make_absolute_externals_relative.unwrap_or(false),
     treeshake: raw_options.treeshake,
     platform: raw_options.platform.unwrap_or(Platform::Browser),
+    http_imports,
+    virtual_modules: raw_options
+      .virtual_modules
+      .map(|map| map.into_iter().collect())
+      .unwrap_or_default(),
+    node_polyfills: raw_options
+      .node_polyfills
+      .map(|map| map.into_iter().collect())
+      .unwrap_or_default(),
     entry_filenames: raw_options.entry_filenames.unwrap_or_else(|| "[name].js".to_string()).into(),
     chunk_filenames: raw_options
       .chunk_filenames
@@ -68,10 +104,39 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     sourcemap: raw_options.sourcemap.unwrap_or(SourceMapType::Hidden),
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
+    legal_comments: raw_options.legal_comments.unwrap_or_default(),
+    comments: raw_options.comments.unwrap_or(false),
+    license_report: raw_options.license_report,
+    css_modules: raw_options.css_modules.unwrap_or_default(),
+    asset_inline_limit: raw_options.asset_inline_limit,
+    css_minify: raw_options.css_minify.unwrap_or(false),
+    css_targets: raw_options.css_targets,
+    public_path,
+    paths: raw_options.paths,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types: loaders,
+    decorators: raw_options.decorators.unwrap_or_default(),
+    drop: raw_options.drop.unwrap_or_default(),
     experimental: raw_options.experimental.unwrap_or_default(),
   };
 
-  NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve }
+  NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve, invalid_options }
+}
+
+/// `runtime` is dropped if it isn't a valid expression, so later stages can parse it without checking again.
+fn normalize_public_path(
+  mut public_path: PublicPathOptions,
+  invalid_options: &mut Vec<(&'static str, String)>,
+) -> PublicPathOptions {
+  if let Some(runtime) = &public_path.runtime {
+    let allocator = Allocator::default();
+    if Parser::new(&allocator, runtime, SourceType::default()).parse_expression().is_err() {
+      invalid_options.push((
+        "publicPath.runtime",
+        format!("it should be a JavaScript expression, but got {runtime:?}"),
+      ));
+      public_path.runtime = None;
+    }
+  }
+  public_path
 }
@@ -0,0 +1,254 @@
+use std::{fmt::Write as _, path::Path, sync::Arc};
+
+use itertools::Itertools;
+use lightningcss::{
+  css_modules::{Config, CssModuleExports, CssModuleReference, Pattern},
+  dependencies::{Dependency, DependencyOptions},
+  rules::{import::ImportRule, CssRule},
+  stylesheet::{MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, StyleSheet},
+  targets::{Browsers, Targets},
+  traits::ToCss,
+};
+use rolldown_common::{CssUrl, NormalizedBundlerOptions};
+use rolldown_utils::ecma_script::is_validate_identifier_name;
+use rustc_hash::FxHashMap;
+
+pub struct ParsedCss {
+  pub ast: StyleSheet<'static, 'static>,
+  /// The js source that represents the stylesheet in the module graph. It imports the stylesheets that are `@import`ed
+  /// by the stylesheet in order, and exports the class names if the stylesheet is a css module.
+  pub facade: String,
+  pub kept_imports: Vec<String>,
+  pub css_modules_pattern: Option<Arc<str>>,
+  /// The urls referenced by `url()`, in the order of appearance.
+  pub url_dependencies: Vec<String>,
+  /// Replacements of `url_dependencies`, which are filled after the referenced files are loaded.
+  pub urls: FxHashMap<String, CssUrl>,
+}
+
+pub fn is_css_module(path: &Path) -> bool {
+  path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.ends_with(".module.css"))
+}
+
+pub fn parse_css(
+  source: &Arc<str>,
+  filename: &str,
+  options: &NormalizedBundlerOptions,
+) -> anyhow::Result<ParsedCss> {
+  let css_modules_pattern: Option<Arc<str>> =
+    is_css_module(Path::new(filename)).then(|| options.css_modules.pattern().into());
+  let css_modules = css_modules_pattern
+    .as_ref()
+    .map(|pattern| -> anyhow::Result<_> {
+      // SAFETY: See the comment of `static_source` below.
+      let pattern: &'static str = unsafe { &*std::ptr::from_ref::<str>(pattern.as_ref()) };
+      Ok(Config {
+        pattern: Pattern::parse(pattern)
+          .map_err(|err| anyhow::format_err!("Invalid `cssModules.pattern`: {err}"))?,
+        dashed_idents: options.css_modules.dashed_idents(),
+        ..Config::default()
+      })
+    })
+    .transpose()?;
+  let targets = css_targets(options)?;
+
+  // SAFETY: The stylesheet borrows from `source` and `css_modules_pattern`. They are stored together in `CssModule`,
+  // which keeps them alive until the stylesheet is dropped.
+  let static_source: &'static str = unsafe { &*std::ptr::from_ref::<str>(source.as_ref()) };
+  let mut ast = StyleSheet::parse(
+    static_source,
+    ParserOptions {
+      filename: filename.to_string(),
+      css_modules,
+      flags: ParserFlags::NESTING,
+      ..ParserOptions::default()
+    },
+  )
+  .map_err(|err| anyhow::format_err!("Failed to parse css file {filename}: {err}"))?;
+
+  let mut facade = String::new();
+  let mut kept_imports = vec![];
+  let mut rules = Vec::with_capacity(ast.rules.0.len());
+  for rule in std::mem::take(&mut ast.rules.0) {
+    let CssRule::Import(import_rule) = rule else {
+      rules.push(rule);
+      continue;
+    };
+    if is_bundleable_import(&import_rule) {
+      let specifier = import_specifier(&import_rule.url);
+      facade.push_str(&format!("import {};\n", serde_json::to_string(specifier.as_ref())?));
+    } else {
+      kept_imports.push(
+        import_rule
+          .to_css_string(PrinterOptions { minify: options.css_minify, ..PrinterOptions::default() })
+          .map_err(|err| anyhow::format_err!("Failed to print css file {filename}: {err}"))?,
+      );
+    }
+  }
+  ast.rules.0 = rules;
+
+  // Syntax lowering is done by both `minify` and printing with the targets.
+  if options.css_minify || options.css_targets.is_some() {
+    ast
+      .minify(MinifyOptions { targets, ..MinifyOptions::default() })
+      .map_err(|err| anyhow::format_err!("Failed to minify css file {filename}: {err}"))?;
+  }
+
+  // The urls and the class names are only known after printing. The project root must be the same as the one used when
+  // rendering the chunk, so that the hashes in the class names are the same.
+  let project_root = options.cwd.to_string_lossy();
+  let printed = ast
+    .to_css(PrinterOptions {
+      project_root: Some(&project_root),
+      analyze_dependencies: Some(DependencyOptions { remove_imports: false }),
+      minify: options.css_minify,
+      targets,
+      ..PrinterOptions::default()
+    })
+    .map_err(|err| anyhow::format_err!("Failed to print css file {filename}: {err}"))?;
+  let url_dependencies = printed
+    .dependencies
+    .unwrap_or_default()
+    .into_iter()
+    .filter_map(|dep| match dep {
+      Dependency::Url(dep) => Some(dep.url),
+      Dependency::Import(_) => None,
+    })
+    .unique()
+    .collect();
+  if css_modules_pattern.is_some() {
+    facade.push_str(&css_module_exports_to_esm(printed.exports.unwrap_or_default())?);
+  }
+
+  Ok(ParsedCss {
+    ast,
+    facade,
+    kept_imports,
+    css_modules_pattern,
+    url_dependencies,
+    urls: FxHashMap::default(),
+  })
+}
+
+/// Parse `cssTargets` like `["chrome87", "safari14.1"]` into the targets of lightningcss.
+pub fn css_targets(options: &NormalizedBundlerOptions) -> anyhow::Result<Targets> {
+  let Some(css_targets) = &options.css_targets else {
+    return Ok(Targets::default());
+  };
+  let mut browsers = Browsers::default();
+  for target in css_targets {
+    let invalid = || anyhow::format_err!("Invalid `cssTargets`: {target:?}");
+    let version_start = target.find(|c: char| c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (name, version) = target.split_at(version_start);
+    let mut parts = version.split('.').map(str::parse::<u32>);
+    let mut next_part = || parts.next().transpose().map_err(|_| invalid());
+    let major = next_part()?.ok_or_else(invalid)?;
+    let minor = next_part()?.unwrap_or(0);
+    let patch = next_part()?.unwrap_or(0);
+    if next_part()?.is_some() || minor > 255 || patch > 255 {
+      return Err(invalid());
+    }
+    let version = (major << 16) | (minor << 8) | patch;
+    let browser = match name.to_ascii_lowercase().as_str() {
+      "android" => &mut browsers.android,
+      "chrome" => &mut browsers.chrome,
+      "edge" => &mut browsers.edge,
+      "firefox" => &mut browsers.firefox,
+      "ie" => &mut browsers.ie,
+      "ios" => &mut browsers.ios_saf,
+      "opera" => &mut browsers.opera,
+      "safari" => &mut browsers.safari,
+      "samsung" => &mut browsers.samsung,
+      _ => return Err(invalid()),
+    };
+    // The oldest version wins if a browser is listed multiple times.
+    *browser = Some(browser.map_or(version, |existing| existing.min(version)));
+  }
+  Ok(browsers.into())
+}
+
+/// Export the class names, so classes that are not used in js could be tree-shaken from the js output.
+fn css_module_exports_to_esm(exports: CssModuleExports) -> anyhow::Result<String> {
+  let mut exports = exports.into_iter().collect::<Vec<_>>();
+  exports.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+  let mut source = String::new();
+  let mut composed_bindings = FxHashMap::default();
+  let mut exported_items_for_default_export = Vec::with_capacity(exports.len());
+  for (idx, (key, export)) in exports.into_iter().enumerate() {
+    // E.g. `"hash_a hash_b " + __composes_0`.
+    let mut parts = vec![];
+    let mut classes = export.name;
+    for reference in export.composes {
+      match reference {
+        CssModuleReference::Local { name } | CssModuleReference::Global { name } => {
+          classes.push(' ');
+          classes.push_str(&name);
+        }
+        CssModuleReference::Dependency { name, specifier } => {
+          classes.push(' ');
+          parts.push(serde_json::to_string(&std::mem::take(&mut classes))?);
+          let specifier = import_specifier(&specifier).into_owned();
+          let next_idx = composed_bindings.len();
+          let binding_idx = *composed_bindings.entry((specifier, name)).or_insert(next_idx);
+          parts.push(format!("__composes_{binding_idx}"));
+        }
+      }
+    }
+    if !classes.is_empty() {
+      parts.push(serde_json::to_string(&classes)?);
+    }
+    let value = parts.join(" + ");
+    if is_validate_identifier_name(&key) {
+      writeln!(source, "export const {key} = {value};")?;
+      exported_items_for_default_export.push(key);
+    } else {
+      let valid_id = format!("key_{idx}");
+      let quoted_key = serde_json::to_string(&key)?;
+      writeln!(source, "const {valid_id} = {value};")?;
+      writeln!(source, "export {{ {valid_id} as {quoted_key} }};")?;
+      exported_items_for_default_export.push(format!("{quoted_key}: {valid_id}"));
+    }
+  }
+  write!(source, "export default {{ {} }};", exported_items_for_default_export.join(", "))?;
+
+  // Composed classes are imported before the exports, so their styles come first.
+  let mut composed_bindings = composed_bindings.into_iter().collect::<Vec<_>>();
+  composed_bindings.sort_unstable_by_key(|(_, binding_idx)| *binding_idx);
+  let mut imports = String::new();
+  for ((specifier, name), binding_idx) in composed_bindings {
+    let imported =
+      if is_validate_identifier_name(&name) { name } else { serde_json::to_string(&name)? };
+    writeln!(
+      imports,
+      "import {{ {imported} as __composes_{binding_idx} }} from {};",
+      serde_json::to_string(&specifier)?
+    )?;
+  }
+  Ok(imports + &source)
+}
+
+/// Imports of remote stylesheets and imports with conditions can't be inlined, so they are kept as they are. Note that
+/// the url of a kept import is not rewritten, so a local stylesheet imported with conditions must be reachable from the
+/// output directory.
+fn is_bundleable_import(rule: &ImportRule) -> bool {
+  !is_remote_url(&rule.url)
+    && rule.layer.is_none()
+    && rule.supports.is_none()
+    && rule.media.media_queries.is_empty()
+}
+
+/// Urls in css are relative by default, while `~` is used to refer to a package.
+pub fn import_specifier(url: &str) -> std::borrow::Cow<'_, str> {
+  if let Some(package) = url.strip_prefix('~') {
+    package.into()
+  } else if url.starts_with("./") || url.starts_with("../") || url.starts_with('/') {
+    url.into()
+  } else {
+    format!("./{url}").into()
+  }
+}
+
+pub fn is_remote_url(url: &str) -> bool {
+  url.starts_with("//") || url.starts_with("data:") || url.contains("://")
+}
@@ -6,6 +6,7 @@ use oxc::{
 };
 use rolldown_common::{ModuleType, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+use rolldown_error::BuildError;
 use rolldown_loader_utils::{binary_to_esm, json_to_esm, text_to_esm};
 use rolldown_plugin::{HookTransformAstArgs, PluginDriver};
 
@@ -29,18 +30,23 @@ pub fn parse_to_ecma_ast(
   options: &NormalizedBundlerOptions,
   module_type: ModuleType,
   source: impl Into<Arc<str>>,
+  errors: &mut Vec<BuildError>,
 ) -> anyhow::Result<(EcmaAst, SymbolTable, ScopeTree)> {
   let source: Arc<str> = source.into();
 
   // 1. Transform the source to the type that rolldown supported.
   let (source, parsed_type) = match module_type {
-    ModuleType::Js => (source, OxcParseType::Js),
+    // The source of a css module is already the js facade of the stylesheet.
+    ModuleType::Js | ModuleType::Css => (source, OxcParseType::Js),
     ModuleType::Jsx => (source, OxcParseType::Jsx),
     ModuleType::Ts => (source, OxcParseType::Ts),
     ModuleType::Tsx => (source, OxcParseType::Tsx),
     ModuleType::Json => (json_to_esm(&source)?.into(), OxcParseType::Js),
     ModuleType::Text => (text_to_esm(&source)?.into(), OxcParseType::Js),
-    ModuleType::Base64 | ModuleType::Dataurl => (text_to_esm(&source)?.into(), OxcParseType::Js),
+    // The source of an asset module is the file name of the emitted asset.
+    ModuleType::Base64 | ModuleType::Dataurl | ModuleType::Asset => {
+      (text_to_esm(&source)?.into(), OxcParseType::Js)
+    }
     ModuleType::Binary => (
       binary_to_esm(&source, options.platform, ROLLDOWN_RUNTIME_RESOURCE_ID).into(),
       OxcParseType::Js,
@@ -63,5 +69,5 @@ pub fn parse_to_ecma_ast(
   ecma_ast =
     plugin_driver.transform_ast(HookTransformAstArgs { cwd: &options.cwd, ast: ecma_ast })?;
 
-  pre_process_ecma_ast(ecma_ast, &parsed_type, path, oxc_source_type)
+  pre_process_ecma_ast(ecma_ast, &parsed_type, path, oxc_source_type, options, errors)
 }
@@ -4,10 +4,14 @@ use oxc::minifier::RemoveDeadCode;
 use oxc::semantic::{ScopeTree, SymbolTable};
 use oxc::span::SourceType;
 use oxc::transformer::{TransformOptions, Transformer};
+use rolldown_common::NormalizedBundlerOptions;
 use rolldown_ecmascript::EcmaAst;
+use rolldown_error::BuildError;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
+use super::drop_code::drop_code;
+use super::lower_decorators::lower_decorators;
 use super::tweak_ast_for_scanning::tweak_ast_for_scanning;
 
 // #[allow(clippy::match_same_arms)]: `OxcParseType::Tsx` will have special logic to deal with ts compared to `OxcParseType::Jsx`
@@ -17,6 +21,8 @@ pub fn pre_process_ecma_ast(
   parse_type: &OxcParseType,
   path: &Path,
   source_type: SourceType,
+  options: &NormalizedBundlerOptions,
+  errors: &mut Vec<BuildError>,
 ) -> anyhow::Result<(EcmaAst, SymbolTable, ScopeTree)> {
   if !matches!(parse_type, OxcParseType::Js) {
     let trivias = ast.trivias.clone();
@@ -48,6 +54,10 @@ pub fn pre_process_ecma_ast(
     // scopes = ret.scopes;
   }
 
+  drop_code(&mut ast, &options.drop);
+
+  errors.extend(lower_decorators(&mut ast, options.decorators, path));
+
   ast.program.with_mut(|fields| {
     RemoveDeadCode::new(fields.allocator).build(fields.program);
   });
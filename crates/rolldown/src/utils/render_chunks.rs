@@ -29,6 +29,8 @@ pub async fn render_chunks<'a>(
         augment_chunk_hash: None,
         file_dir: chunk.file_dir,
         preliminary_filename: chunk.preliminary_filename,
+        legal_comments: chunk.legal_comments,
+        css: chunk.css,
       })
   }))
   .await
@@ -1,3 +1,4 @@
+use oxc::span::Span;
 use rolldown_common::EcmaModule;
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_sourcemap::{collapse_sourcemaps, lines_count, RawSource, Source, SourceMapSource};
@@ -9,6 +10,7 @@ pub fn render_ecma_module(
   ast: &EcmaAst,
   source_name: &str,
   options: &SharedOptions,
+  legal_comments: &[(Span, &str)],
 ) -> Option<Vec<Box<dyn Source + Send>>> {
   if ast.is_body_empty() {
     None
@@ -24,7 +26,8 @@ pub fn render_ecma_module(
     // Because oxc codegen sourcemap is last of sourcemap chain,
     // If here no extra sourcemap need remapping, we using it as final module sourcemap.
     // So here make sure using correct `source_name` and `source_content.
-    let render_output = EcmaCompiler::print(ast, source_name, enable_sourcemap);
+    let render_output =
+      EcmaCompiler::print(ast, source_name, enable_sourcemap, options.comments, legal_comments);
 
     if enable_sourcemap {
       let sourcemap = if module.sourcemap_chain.is_empty() {
@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use rolldown_common::{
+  IndexModules, LicenseReportFormat, LicenseReportOptions, Module, Output, OutputAsset,
+  PackageJson, ThirdPartyLicense,
+};
+use rolldown_fs::FileSystem;
+use rustc_hash::FxHashSet;
+
+const LICENSE_FILENAMES: [&str; 6] =
+  ["LICENSE", "LICENSE.md", "LICENSE.txt", "LICENCE", "LICENCE.md", "LICENCE.txt"];
+
+/// Collect the npm packages that have at least one module included in the bundle.
+pub fn collect_third_party_licenses(
+  modules: &IndexModules,
+  fs: &impl FileSystem,
+) -> Vec<ThirdPartyLicense> {
+  let mut visited = FxHashSet::default();
+  let mut licenses = modules
+    .iter()
+    .filter_map(Module::as_ecma)
+    .filter(|m| m.is_included)
+    .filter_map(|m| m.package_json.as_deref())
+    .filter(|pkg_json| pkg_json.is_third_party() && visited.insert(&pkg_json.path))
+    .filter_map(|pkg_json| {
+      let (name, pkg_json) = match &pkg_json.name {
+        Some(name) => (name.clone(), Cow::Borrowed(pkg_json)),
+        None => {
+          let pkg_json = find_package_root(pkg_json, fs)?;
+          (pkg_json.name.clone()?, Cow::Owned(pkg_json))
+        }
+      };
+      let dir = pkg_json.path.parent()?;
+      let license_text = LICENSE_FILENAMES
+        .iter()
+        .map(|filename| dir.join(filename))
+        .find(|path| fs.exists(path))
+        .and_then(|path| fs.read(&path).ok())
+        .map(|content| String::from_utf8_lossy(&content).trim_end().to_string());
+      Some(ThirdPartyLicense {
+        name,
+        version: pkg_json.version.clone(),
+        license: pkg_json.license.clone(),
+        license_text,
+      })
+    })
+    .collect::<Vec<_>>();
+  licenses.sort();
+  // The same package might be installed in multiple places.
+  licenses.dedup_by(|a, b| a.name == b.name && a.version == b.version);
+  licenses
+}
+
+/// Nested `package.json`s like `{ "type": "module" }` don't describe a package. Look for the nearest one with a `name`
+/// in the parent directories, without leaving the `node_modules` directory the package is installed in.
+fn find_package_root(pkg_json: &PackageJson, fs: &impl FileSystem) -> Option<PackageJson> {
+  pkg_json
+    .path
+    .ancestors()
+    .skip(2)
+    .take_while(|dir| dir.file_name().is_some_and(|name| name != "node_modules"))
+    .map(|dir| dir.join("package.json"))
+    .filter(|path| fs.exists(path))
+    .filter_map(|path| {
+      let raw_json = serde_json::from_slice::<serde_json::Value>(&fs.read(&path).ok()?).ok()?;
+      Some(PackageJson::new(path).with_license_info(&raw_json))
+    })
+    .find(|pkg_json| pkg_json.name.is_some())
+}
+
+pub fn render_license_report(
+  options: &LicenseReportOptions,
+  licenses: &[ThirdPartyLicense],
+) -> Output {
+  let source = match options.format() {
+    LicenseReportFormat::Text => licenses
+      .iter()
+      .map(|license| {
+        let mut section = license.name.clone();
+        if let Some(version) = &license.version {
+          section.push_str(&format!("@{version}"));
+        }
+        section
+          .push_str(&format!("\nLicense: {}\n", license.license.as_deref().unwrap_or("UNKNOWN")));
+        if let Some(license_text) = &license.license_text {
+          section.push_str(&format!("\n{license_text}\n"));
+        }
+        section
+      })
+      .collect::<Vec<_>>()
+      .join("\n---\n\n"),
+    LicenseReportFormat::Json => {
+      let value = licenses
+        .iter()
+        .map(|license| {
+          serde_json::json!({
+            "name": license.name,
+            "version": license.version,
+            "license": license.license,
+            "licenseText": license.license_text,
+          })
+        })
+        .collect::<Vec<_>>();
+      let mut json = serde_json::to_string_pretty(&value).expect("should be valid json");
+      json.push('\n');
+      json
+    }
+  };
+  Output::Asset(Box::new(OutputAsset {
+    filename: options.file_name().to_string(),
+    source: source.into(),
+  }))
+}
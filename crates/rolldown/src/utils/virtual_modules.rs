@@ -0,0 +1,64 @@
+use rolldown_common::{ModuleDefFormat, NormalizedBundlerOptions, ResolvedRequestInfo};
+
+/// Resolves `specifier` to a module of `virtual_modules`. Relative specifiers in a virtual module are joined with the
+/// id of the importer first, so `./b.js` in `virtual:lib/a.js` refers to `virtual:lib/b.js`.
+pub fn resolve_virtual_module(
+  options: &NormalizedBundlerOptions,
+  specifier: &str,
+  importer: Option<&str>,
+) -> Option<ResolvedRequestInfo> {
+  if options.virtual_modules.is_empty() {
+    return None;
+  }
+  let id = importer
+    .filter(|importer| is_relative(specifier) && options.virtual_modules.contains_key(*importer))
+    .map(|importer| join_virtual_id(importer, specifier))
+    .filter(|joined| options.virtual_modules.contains_key(joined))
+    .or_else(|| options.virtual_modules.contains_key(specifier).then(|| specifier.to_string()))?;
+  Some(ResolvedRequestInfo {
+    module_type: ModuleDefFormat::from_path(&id),
+    path: id.into(),
+    is_external: false,
+    package_json: None,
+    side_effects: None,
+  })
+}
+
+fn is_relative(specifier: &str) -> bool {
+  specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// Joins a relative specifier with the directory of a virtual id. The scheme of the id, like `virtual:`, is kept, and
+/// `..` never leaves it.
+fn join_virtual_id(importer: &str, specifier: &str) -> String {
+  let (scheme, path) = match importer.find(':') {
+    Some(idx) if !importer[..idx].contains('/') => importer.split_at(idx + 1),
+    _ => ("", importer),
+  };
+  let mut segments = path.split('/').collect::<Vec<_>>();
+  // The last segment is the name of the importer
+  segments.pop();
+  for segment in specifier.split('/') {
+    match segment {
+      "." | "" => {}
+      // An empty segment is the root of an absolute id
+      ".." => {
+        if segments.last().is_some_and(|segment| !segment.is_empty()) {
+          segments.pop();
+        }
+      }
+      _ => segments.push(segment),
+    }
+  }
+  format!("{scheme}{}", segments.join("/"))
+}
+
+#[test]
+fn test_join_virtual_id() {
+  assert_eq!(join_virtual_id("virtual:config", "./env.js"), "virtual:env.js");
+  assert_eq!(join_virtual_id("virtual:lib/a.js", "./b.js"), "virtual:lib/b.js");
+  assert_eq!(join_virtual_id("virtual:lib/nested/a.js", "../b.js"), "virtual:lib/b.js");
+  assert_eq!(join_virtual_id("virtual:a.js", "../../b.js"), "virtual:b.js");
+  assert_eq!(join_virtual_id("/src/generated/a.js", "./b.js"), "/src/generated/b.js");
+  assert_eq!(join_virtual_id("/a.js", "../b.js"), "/b.js");
+}
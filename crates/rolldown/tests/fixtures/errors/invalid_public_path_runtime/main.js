@@ -0,0 +1 @@
+export default new URL('./main.js', import.meta.url).href
@@ -0,0 +1,12 @@
+const dec = (_value: unknown, _context: unknown) => {}
+const inject = (_target: unknown, _key: string, _index: number) => {}
+
+export class Counter {
+  @dec #count = 0
+}
+
+export class Service {
+  method(@inject value: string) {
+    return value
+  }
+}
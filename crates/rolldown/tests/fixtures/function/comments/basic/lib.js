@@ -0,0 +1,34 @@
+/**
+ * The version of the library.
+ * @type {string}
+ */
+export const VERSION = '1.0.0'
+
+// Line comments are not kept.
+/**
+ * Add two numbers.
+ * @param {number} a
+ * @param {number} b
+ * @returns {number}
+ */
+export function add(a, b) {
+  /* Comments inside statements are not kept */
+  return a + b
+}
+
+/** A point in 2D space. */
+export default class Base {}
+
+/** @license lib-license */
+/**
+ * A point.
+ */
+export class Point extends Base {
+  constructor(x, y) {
+    super()
+    this.x = x
+    this.y = y
+  }
+}
+
+export const noop = /* @__PURE__ */ Object.freeze({})
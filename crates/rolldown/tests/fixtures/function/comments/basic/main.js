@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import { add, Point, VERSION } from './lib'
+
+/**
+ * Entry point.
+ */
+assert.strictEqual(add(1, 2), 3)
+assert.strictEqual(new Point(1, 2).x, 1)
+assert.strictEqual(VERSION, '1.0.0')
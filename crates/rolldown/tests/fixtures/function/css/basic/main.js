@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import './style.css'
+
+assert.strictEqual(typeof document, 'undefined')
@@ -0,0 +1,4 @@
+import './shared.js'
+import './lazy-a.css'
+
+export default 'a'
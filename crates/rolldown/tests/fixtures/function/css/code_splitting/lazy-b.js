@@ -0,0 +1,4 @@
+import './shared.js'
+import './lazy-b.css'
+
+export default 'b'
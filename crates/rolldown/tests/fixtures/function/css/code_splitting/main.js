@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import './main.css'
+
+const [a, b] = await Promise.all([import('./lazy-a.js'), import('./lazy-b.js')])
+assert.strictEqual(a.default, 'a')
+assert.strictEqual(b.default, 'b')
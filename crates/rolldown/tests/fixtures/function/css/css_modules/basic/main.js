@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import styles, { button } from './button.module.css'
+
+assert.strictEqual(styles.button, button)
+assert.strictEqual(styles['primary-button'], `${button.split(' ')[0].replace('button', 'primary-button')} ${button}`)
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { title } from './title.module.css'
+
+assert.strictEqual(title.split(' ').length, 3)
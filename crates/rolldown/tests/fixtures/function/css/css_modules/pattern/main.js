@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import styles from './card.module.css'
+
+assert.deepStrictEqual(styles, { card: 'card-module__card' })
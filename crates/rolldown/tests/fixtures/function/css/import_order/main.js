@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import './a.css'
+import './b.css'
+
+assert.ok(true)
@@ -0,0 +1,3 @@
+import './styles/index.css'
+
+export const value = 1
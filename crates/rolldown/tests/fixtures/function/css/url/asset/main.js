@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+import './style.css'
+
+const css = fs.readFileSync(path.join(import.meta.dirname, 'main.css'), 'utf-8')
+const urls = [...css.matchAll(/url\("([^"]+)"\)/g)].map((m) => m[1])
+assert.strictEqual(urls.length, 3)
+assert.match(urls[1], /\?v=1#hash$/)
+for (const url of urls) {
+  assert(fs.existsSync(path.join(import.meta.dirname, url.replace(/[?#].*$/, ''))))
+}
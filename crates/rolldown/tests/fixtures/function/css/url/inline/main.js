@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+import './style.css'
+
+const css = fs.readFileSync(path.join(import.meta.dirname, 'main.css'), 'utf-8')
+assert(css.includes('url("data:image/svg+xml'))
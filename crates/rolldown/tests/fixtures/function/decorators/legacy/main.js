@@ -0,0 +1,55 @@
+import assert from 'node:assert'
+
+const calls = []
+
+function log(name) {
+  return (target, key, descriptor) => {
+    calls.push(`${name}:${typeof key === 'string' ? key : 'class'}`)
+    return descriptor
+  }
+}
+
+function readonly(target, key, descriptor) {
+  descriptor.writable = false
+  return descriptor
+}
+
+function sealed(constructor) {
+  return class extends constructor {
+    sealed = true
+  }
+}
+
+const key = 'computed'
+
+@sealed
+@log('class')
+class Foo {
+  @log('field') field = 1
+
+  @log('first')
+  @log('second')
+  @readonly
+  method() {
+    return 'method'
+  }
+
+  @log('computed') [key]() {}
+
+  @log('static') static staticMethod() {}
+}
+
+export default @log('default') class {}
+
+assert.deepStrictEqual(calls, [
+  'field:field',
+  'second:method',
+  'first:method',
+  'computed:computed',
+  'static:staticMethod',
+  'class:class',
+  'default:class',
+])
+assert.strictEqual(new Foo().sealed, true)
+assert.strictEqual(new Foo().method(), 'method')
+assert.strictEqual(Object.getOwnPropertyDescriptor(Object.getPrototypeOf(Foo).prototype, 'method').writable, false)
@@ -0,0 +1,25 @@
+import assert from 'node:assert'
+
+const injections: [string | undefined, number][] = []
+
+function inject(target: any, key: string | undefined, index: number) {
+  injections.push([key, index])
+}
+
+function component(constructor: Function) {
+  ;(constructor as any).isComponent = true
+}
+
+@component
+class Service {
+  constructor(@inject name: string, @inject version: number) {}
+
+  handle(input: string, @inject callback: () => void): void {}
+}
+
+assert.deepStrictEqual(injections, [
+  ['handle', 1],
+  [undefined, 1],
+  [undefined, 0],
+])
+assert.strictEqual((Service as any).isComponent, true)
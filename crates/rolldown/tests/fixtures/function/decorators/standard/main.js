@@ -0,0 +1,86 @@
+import assert from 'node:assert'
+
+const events = []
+
+function logged(value, context) {
+  events.push(`decorate ${context.kind} ${String(context.name)}${context.static ? ' static' : ''}`)
+  context.metadata[context.name] = context.kind
+  context.addInitializer(function () {
+    events.push(`init ${context.kind} ${String(context.name)}`)
+  })
+  if (context.kind === 'method') {
+    return function (...args) {
+      return `logged ${value.call(this, ...args)}`
+    }
+  }
+  if (context.kind === 'field') {
+    return (initial) => initial * 2
+  }
+  if (context.kind === 'accessor') {
+    return {
+      get() {
+        return value.get.call(this) + 1
+      },
+      set(v) {
+        value.set.call(this, v)
+      },
+      init: (initial) => initial * 10,
+    }
+  }
+}
+
+function register(value, context) {
+  events.push(`decorate ${context.kind} ${context.name}`)
+  context.addInitializer(function () {
+    events.push(`init class ${this.name}`)
+  })
+  return class Registered extends value {
+    registered = true
+  }
+}
+
+@register
+class Foo {
+  @logged field = 1
+  @logged accessor count = 2
+  @logged static staticField = 3
+
+  @logged
+  method() {
+    return 'method'
+  }
+
+  @logged
+  static create() {
+    return 'created'
+  }
+}
+
+assert.deepStrictEqual(events, [
+  'decorate method create static',
+  'decorate accessor count',
+  'decorate method method',
+  'decorate field staticField static',
+  'decorate field field',
+  'init method create',
+  'init field staticField',
+  'decorate class Foo',
+  'init class Registered',
+])
+
+const foo = new Foo()
+assert.strictEqual(Foo.create(), 'logged created')
+assert.strictEqual(foo.registered, true)
+assert.strictEqual(foo.field, 2)
+assert.strictEqual(foo.count, 21)
+assert.strictEqual(foo.method(), 'logged method')
+assert.strictEqual(Foo.staticField, 6)
+assert.deepStrictEqual(
+  { ...Foo[Symbol.metadata ?? Symbol.for('Symbol.metadata')] },
+  { field: 'field', count: 'accessor', staticField: 'field', method: 'method', create: 'method' },
+)
+assert.deepStrictEqual(events.slice(9), ['init method method', 'init field field', 'init accessor count'])
+
+export default @logged class {
+  static value = 1
+}
@@ -0,0 +1,33 @@
+import assert from 'node:assert'
+
+let count = 0
+const increase = () => ++count
+
+console.log(increase())
+console.warn.call(console, increase())
+const value = console.error(increase()) ?? 'dropped'
+console.log?.(increase())
+console?.log(increase())
+const optionalValue = console?.error?.(increase()) ?? 'dropped'
+if (count > 0) debugger
+
+DEV: {
+  increase()
+}
+DEV: for (let i = 0; i < 10; i++) increase()
+
+KEEP: {
+  increase()
+}
+
+const logged = []
+function withLocalConsole(console) {
+  console.log('kept')
+}
+withLocalConsole({ log: (msg) => logged.push(msg) })
+console.info(increase())
+
+assert.strictEqual(count, 1)
+assert.strictEqual(value, 'dropped')
+assert.strictEqual(optionalValue, 'dropped')
+assert.deepStrictEqual(logged, ['kept'])
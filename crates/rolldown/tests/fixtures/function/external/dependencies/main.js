@@ -0,0 +1,5 @@
+import dep from 'dep'
+import peer from 'peer-dep/sub'
+import './local.js'
+
+console.log(dep, peer)
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { value } from 'vendor-lib'
+
+assert.equal(value, 'vendor')
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { existsSync } from 'fs'
+import { join } from 'path/posix'
+
+assert.equal(typeof existsSync, 'function')
+assert.equal(join('a', 'b'), 'a/b')
+
+export const lazy = () => [import('@scope/pkg'), import('virtual-foo/bar')]
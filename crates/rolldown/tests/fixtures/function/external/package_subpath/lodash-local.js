@@ -0,0 +1 @@
+console.log('lodash-local is bundled')
@@ -0,0 +1,5 @@
+import lodash from 'lodash'
+import merge from 'lodash/merge'
+import './lodash-local.js'
+
+console.log(lodash, merge)
@@ -0,0 +1,8 @@
+import React from 'react'
+import merge from 'lodash/merge'
+import 'lodash'
+
+export * from 'react'
+export const lazy = () => import('react')
+
+console.log(React, merge)
@@ -0,0 +1,6 @@
+// @preserve shared notice
+// A normal comment that is not kept.
+export const bar = 'bar'
+
+/*! bar helpers | MIT */
+export const baz = 'baz'
@@ -0,0 +1,6 @@
+/**
+ * @license foo
+ * Copyright (c) foo
+ */
+// @preserve shared notice
+export const foo = 'foo'
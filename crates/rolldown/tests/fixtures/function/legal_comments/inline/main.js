@@ -0,0 +1,9 @@
+/*! main.js v1.0.0 | MIT */
+import assert from 'node:assert'
+import { foo } from './foo'
+import { bar, baz } from './bar'
+
+assert.strictEqual(foo, 'foo')
+assert.strictEqual(bar, 'bar')
+assert.strictEqual(baz, 'baz')
+//! end of main.js
@@ -0,0 +1,3 @@
+// @preserve shared notice
+// A normal comment that is not kept.
+export const bar = 'bar'
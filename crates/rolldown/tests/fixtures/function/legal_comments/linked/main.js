@@ -0,0 +1,7 @@
+/*! main.js v1.0.0 | MIT */
+import assert from 'node:assert'
+import { foo } from './foo'
+import { bar } from './bar'
+
+assert.strictEqual(foo, 'foo')
+assert.strictEqual(bar, 'bar')
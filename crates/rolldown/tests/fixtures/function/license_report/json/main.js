@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { a } from 'pkg-a'
+import { b } from 'pkg-b'
+import { unused } from 'unused-pkg'
+
+assert.strictEqual(a, 'a')
+assert.strictEqual(b, 'b')
@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { fileURLToPath } from 'node:url'
+
+const wasmUrl = new URL('./data.wasm', import.meta.url)
+const textUrl = new URL('./data.txt', import.meta.url)
+
+assert.match(wasmUrl.href, /\/assets\/data-.+\.wasm$/)
+assert.strictEqual(fs.readFileSync(fileURLToPath(wasmUrl), 'utf8'), '\0asm\n')
+assert.strictEqual(fs.readFileSync(fileURLToPath(textUrl), 'utf8'), 'hello\n')
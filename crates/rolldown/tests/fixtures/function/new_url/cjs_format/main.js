@@ -0,0 +1,2 @@
+// `import.meta.url` is replaced by the url of the output file
+export const textUrl = new URL('./data.txt', import.meta.url)
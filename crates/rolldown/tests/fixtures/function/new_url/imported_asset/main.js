@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { fileURLToPath } from 'node:url'
+import data from './data.json'
+
+const dataUrl = new URL('./data.json', import.meta.url)
+
+assert.strictEqual(data.value, 'data')
+assert.match(dataUrl.href, /\/assets\/data-.+\.json$/)
+assert.deepStrictEqual(JSON.parse(fs.readFileSync(fileURLToPath(dataUrl), 'utf8')), data)
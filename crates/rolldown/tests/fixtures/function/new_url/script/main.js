@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+
+const workerUrl = new URL('./worker.ts', import.meta.url)
+
+assert.match(workerUrl.href, /\/worker\.mjs$/)
+const { name } = await import(workerUrl.href)
+assert.strictEqual(name, 'worker')
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { readFile } from './storage.js'
+
+assert.strictEqual(typeof readFile, 'function')
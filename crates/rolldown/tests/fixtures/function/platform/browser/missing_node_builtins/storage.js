@@ -0,0 +1,6 @@
+import { readFileSync } from 'fs'
+import { join } from 'node:path'
+
+export function readFile(name) {
+  return readFileSync(join('data', name), 'utf8')
+}
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { join } from 'path'
+import * as path from 'node:path'
+
+assert.strictEqual(join('a', 'b'), 'a/b')
+assert.strictEqual(path.join, join)
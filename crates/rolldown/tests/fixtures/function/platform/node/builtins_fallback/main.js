@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import { readFileSync } from 'fs'
+import { EOL } from 'os'
+import { format } from 'util'
+
+assert.strictEqual(typeof readFileSync, 'function')
+assert.strictEqual(typeof EOL, 'string')
+assert.strictEqual(format(), 'virtual util')
+import('node:not-a-builtin').catch(() => {})
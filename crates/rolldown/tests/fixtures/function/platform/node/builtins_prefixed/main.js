@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { readFileSync } from 'fs'
+import { join } from 'node:path'
+import * as path from 'path'
+
+assert.strictEqual(typeof readFileSync, 'function')
+assert.strictEqual(join, path.join)
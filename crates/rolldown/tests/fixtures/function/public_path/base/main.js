@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import logo from './logo.svg'
+
+const workerUrl = new URL('./worker.js', import.meta.url)
+
+assert.match(logo, /^https:\/\/cdn\.example\.com\/static\/assets\/logo-.+\.svg$/)
+assert.strictEqual(workerUrl.href, 'https://cdn.example.com/static/worker.mjs')
+// Dynamic imports are kept relative by default
+assert.strictEqual((await import('./lazy.js')).lazy, 'lazy')
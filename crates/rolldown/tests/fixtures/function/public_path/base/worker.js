@@ -0,0 +1 @@
+globalThis.postMessage?.('worker')
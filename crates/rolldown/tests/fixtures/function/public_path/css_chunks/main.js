@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+
+const lazy = await import('./lazy.js')
+assert.strictEqual(lazy.default, 'lazy')
@@ -0,0 +1,8 @@
+import './setup.js'
+import assert from 'node:assert'
+import logo from './logo.svg'
+
+const logoUrl = new URL('./logo.svg', import.meta.url)
+
+assert.match(logo, /^https:\/\/cdn\.example\.com\/assets\/logo-.+\.svg$/)
+assert.strictEqual(logoUrl.href, logo)
@@ -0,0 +1 @@
+globalThis.__CDN__ = 'https://cdn.example.com/'
@@ -0,0 +1,12 @@
+import assert from 'assert-alias'
+import { util } from '@app/util.js'
+import { nested } from '@lib/nested/value.js'
+import { react } from 'react'
+import { preact } from './src/preact.js'
+import { cpp } from 'c++'
+
+assert.equal(util, 'util nested')
+assert.equal(nested, 'nested')
+assert.equal(react, 'react')
+assert.equal(preact, 'preact')
+assert.equal(cpp, 'cpp')
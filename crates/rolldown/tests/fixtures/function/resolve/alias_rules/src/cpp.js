@@ -0,0 +1 @@
+export const cpp = 'cpp'
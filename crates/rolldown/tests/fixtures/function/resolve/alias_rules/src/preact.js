@@ -0,0 +1 @@
+export const preact = 'preact'
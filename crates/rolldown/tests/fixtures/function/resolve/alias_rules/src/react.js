@@ -0,0 +1 @@
+export const react = 'react'
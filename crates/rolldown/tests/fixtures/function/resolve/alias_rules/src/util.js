@@ -0,0 +1,3 @@
+import { nested } from '@app/nested/value.js'
+
+export const util = `util ${nested}`
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { nodeOnly, readFile } from 'lib'
+
+assert.strictEqual(nodeOnly, undefined)
+assert.strictEqual(readFile, undefined)
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import value from 'data:text/javascript,export default 42'
+import { answer } from 'data:text/javascript;charset=utf-8,export%20const%20answer%20%3D%20value%20%3D%3E%20value'
+import json from 'data:application/json;base64,eyJuYW1lIjoicm9sbGRvd24ifQ=='
+import text from 'data:text/plain,hello%20world'
+import image from 'data:image/svg+xml,%3Csvg%2F%3E'
+
+assert.strictEqual(value, 42)
+assert.strictEqual(answer(1), 1)
+assert.deepStrictEqual(json, { name: 'rolldown' })
+assert.strictEqual(text, 'hello world')
+assert.strictEqual(image, 'data:image/svg+xml,%3Csvg%2F%3E')
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { value } from './foo.js'
+
+assert.equal(value, 'ts')
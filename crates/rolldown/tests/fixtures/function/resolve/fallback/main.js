@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { value } from 'missing-package'
+
+assert.equal(value, 'shim')
@@ -0,0 +1,4 @@
+import { a } from './foo.js'
+import { b } from './foo'
+
+console.log(a, b)
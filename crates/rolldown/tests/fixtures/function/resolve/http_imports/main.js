@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { add } from 'https://cdn.example.com/lib/math.js?v=1'
+
+assert.strictEqual(add(1, 2), 3)
+
+// There is no local copy, so it stays external.
+export const loadMissing = () => import('https://cdn.example.com/missing.js')
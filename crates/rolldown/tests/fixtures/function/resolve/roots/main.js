@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { value } from '/lib/util.js'
+
+assert.equal(value, 'util')
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { a } from './packages/a/src/index.ts'
+import { b } from './packages/b/src/index.ts'
+
+assert.equal(a, 'a uses a-helper')
+assert.equal(b, 'b uses b-helper')
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import config from 'virtual:config'
+import data from 'virtual:data'
+import message from 'virtual:message.txt'
+import shadowed from 'shadowed'
+
+assert.deepStrictEqual(config, { mode: 'production' })
+assert.strictEqual(data.answer, 42)
+// The module type is inferred from the extension of the id.
+assert.strictEqual(message, 'hello')
+// Virtual modules are resolved before the file system.
+assert.strictEqual(shadowed, 'virtual')
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { add, double, version } from 'virtual:lib/index.js'
+
+assert.strictEqual(add(1, 2), 3)
+assert.strictEqual(double(2), 4)
+// There's no virtual `virtual:lib/version.js`, so `./version.js` is a file relative to `cwd`.
+assert.strictEqual(version, '1.0.0')
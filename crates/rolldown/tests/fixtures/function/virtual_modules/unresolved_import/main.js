@@ -0,0 +1,3 @@
+import config from 'virtual:config'
+
+export { config }
@@ -0,0 +1,19 @@
+import assert from 'node:assert'
+import { shared } from './shared.js'
+
+class FakeWorker {
+  constructor(url, options) {
+    this.url = url
+    this.options = options
+  }
+}
+globalThis.Worker = FakeWorker
+globalThis.SharedWorker = FakeWorker
+
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+const sharedWorker = new SharedWorker(new URL('./shared-worker.js', import.meta.url))
+
+assert.match(worker.url.href, /\/worker\.mjs$/)
+assert.deepStrictEqual(worker.options, { type: 'module' })
+assert.match(sharedWorker.url.href, /\/shared-worker\.mjs$/)
+assert.strictEqual(shared, 'shared')
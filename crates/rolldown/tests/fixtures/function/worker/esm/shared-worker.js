@@ -0,0 +1 @@
+globalThis.onconnect = (e) => e.ports[0].postMessage('connected')
@@ -0,0 +1,3 @@
+import { value } from './value.js'
+
+globalThis.postMessage?.(value)
@@ -0,0 +1,5 @@
+import * as ns from './shared.js'
+
+// The worker gets its own copy of `shared.js` and the runtime module, since chunks of the iife format can't import
+// each other.
+console.log(ns, new Worker(new URL('./worker.js', import.meta.url)))
@@ -0,0 +1,3 @@
+import * as ns from './shared.js'
+
+postMessage(ns)
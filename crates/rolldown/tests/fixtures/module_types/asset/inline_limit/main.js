@@ -0,0 +1,15 @@
+import assert from 'node:assert'
+import small from './small.svg'
+import large from './large.svg'
+import largeInline from './large.svg?inline'
+import smallUrl from './small.svg?url'
+import textUrl from './data.txt?url'
+
+const smallNewUrl = new URL('./small.svg', import.meta.url)
+
+assert.match(small, /^data:image\/svg\+xml/)
+assert.match(large, /^\.\/assets\/large-.+\.svg$/)
+assert.match(largeInline, /^data:image\/svg\+xml/)
+assert.match(smallUrl, /^\.\/assets\/small-.+\.svg$/)
+assert.match(textUrl, /^\.\/assets\/data-.+\.txt$/)
+assert.strictEqual(smallNewUrl.href, small)
@@ -0,0 +1 @@
+import './dist/entries/main.mjs'
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+import url from './logo.svg'
+
+assert.match(url, /^\.\.\/assets\/logo-.+\.svg$/)
+assert(fs.existsSync(path.join(import.meta.dirname, url)))
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import large from './large.svg'
+
+// `dataurl` modules are always inlined unless `assetInlineLimit` is set
+assert.match(large, /^data:image\/svg\+xml/)
@@ -0,0 +1,11 @@
+const registry = []
+
+function register(value) {
+  registry.push(value)
+}
+
+// Unused, but the decorator call is kept as it may have side effects.
+@register
+class Registered {}
+
+globalThis.registry = registry
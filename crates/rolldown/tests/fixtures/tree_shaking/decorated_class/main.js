@@ -0,0 +1,2 @@
+import './pure.js'
+import './decorated.js'
@@ -0,0 +1,6 @@
+function dec(value) {
+  return value
+}
+
+// Unused and annotated as pure, so it's removed.
+export const Pure = /* @__PURE__ */ @dec class {}
@@ -0,0 +1,11 @@
+console.log('logger loaded')
+
+DEV: {
+  globalThis.__DEV_LOGGER__ = true
+}
+
+export function used() {}
+
+export function unused() {
+  console.info('unused')
+}
@@ -0,0 +1,3 @@
+import { used } from './logger.js'
+
+used()
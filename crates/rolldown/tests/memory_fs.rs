@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_fs::{FileSystem as _, MemoryFileSystem};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn build_in_memory() {
+  let files = [
+    (
+      "/project/main.js",
+      "import { greet } from './greet.js'\nimport pkg from 'pkg'\nconsole.log(greet(pkg))",
+    ),
+    ("/project/greet.js", "export const greet = (name) => `hello ${name}`"),
+    ("/project/node_modules/pkg/package.json", r#"{ "name": "pkg", "main": "index.js" }"#),
+    ("/project/node_modules/pkg/index.js", "export default 'pkg'"),
+  ]
+  .map(|(path, content)| (path.to_string(), content.to_string()));
+  let files = files.iter().map(|(path, content)| (path, content)).collect::<Vec<_>>();
+  let fs = MemoryFileSystem::new(&files);
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(PathBuf::from("/project")),
+      ..Default::default()
+    })
+    .with_fs(fs.clone())
+    .build();
+  let output = bundler.write().await.expect("The build should succeed");
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let written = fs.read(Path::new("/project/dist/main.js")).expect("main.js should be written");
+  let written = String::from_utf8(written).unwrap();
+  assert!(written.contains("hello ${name}"), "{written}");
+  assert!(written.contains("var pkg_index_default = 'pkg'"), "{written}");
+  assert!(!Path::new("/project").exists());
+}
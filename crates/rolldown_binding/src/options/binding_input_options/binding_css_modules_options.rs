@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingCssModulesOptions {
+  pub pattern: Option<String>,
+  pub dashed_idents: Option<bool>,
+}
+
+impl From<BindingCssModulesOptions> for rolldown::CssModulesOptions {
+  fn from(value: BindingCssModulesOptions) -> Self {
+    Self { pattern: value.pattern, dashed_idents: value.dashed_idents }
+  }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingDropOptions {
+  pub console: Option<bool>,
+  pub debugger: Option<bool>,
+  pub labels: Option<Vec<String>>,
+}
+
+impl From<BindingDropOptions> for rolldown::DropOptions {
+  fn from(value: BindingDropOptions) -> Self {
+    Self { console: value.console, debugger: value.debugger, labels: value.labels }
+  }
+}
@@ -1,12 +1,18 @@
-use crate::types::binding_resolve_alias_item::AliasItem;
+use crate::types::{
+  binding_resolve_alias_item::{AliasItem, AliasRuleItem, ExtensionAliasItem},
+  js_callback::MaybeAsyncJsCallbackExt,
+};
+use rolldown::AliasRule;
+use rolldown_common::js_regex::HybridRegex;
 use serde::Deserialize;
 
-#[napi_derive::napi(object)]
+#[napi_derive::napi(object, object_to_js = false)]
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct BindingResolveOptions {
   // Option<Vec<(String, Vec<String>)>>> is better, maybe NAPI-RS should support tuples.
   pub alias: Option<Vec<AliasItem>>,
+  pub alias_rules: Option<Vec<AliasRuleItem>>,
   pub alias_fields: Option<Vec<Vec<String>>>,
   pub condition_names: Option<Vec<String>>,
   pub exports_fields: Option<Vec<Vec<String>>>,
@@ -16,17 +22,52 @@ pub struct BindingResolveOptions {
   pub modules: Option<Vec<String>>,
   pub symlinks: Option<bool>,
   pub tsconfig_filename: Option<String>,
+  pub tsconfig_discovery: Option<bool>,
+  pub extension_alias: Option<Vec<ExtensionAliasItem>>,
+  pub fallback: Option<Vec<AliasItem>>,
+  pub roots: Option<Vec<String>>,
+  pub restrictions: Option<Vec<String>>,
+  pub fully_specified: Option<bool>,
+  pub prefer_relative: Option<bool>,
+  pub prefer_absolute: Option<bool>,
 }
 
-impl From<BindingResolveOptions> for rolldown::ResolveOptions {
-  fn from(value: BindingResolveOptions) -> Self {
-    Self {
-      alias: value.alias.map(|alias| {
-        alias
-          .into_iter()
-          .map(|alias_item| (alias_item.find, alias_item.replacements))
-          .collect::<Vec<_>>()
-      }),
+fn alias_rule_item_to_rule(item: AliasRuleItem) -> anyhow::Result<AliasRule> {
+  let rule = if let Some(ts_fn) = item.find_fn {
+    AliasRule::from_fn(move |specifier, importer| {
+      let specifier = specifier.to_string();
+      let importer = importer.map(ToString::to_string);
+      let ts_fn = ts_fn.clone();
+      Box::pin(
+        async move { ts_fn.await_call((specifier, importer)).await.map_err(anyhow::Error::from) },
+      )
+    })
+  } else {
+    let find =
+      item.find.ok_or_else(|| anyhow::anyhow!("An alias rule needs `find` or `findFn`"))?;
+    let replacement = item.replacement.unwrap_or_default();
+    match item.flags {
+      Some(flags) => AliasRule::regex(HybridRegex::with_flags(&find, &flags)?, replacement),
+      None => AliasRule::exact(find, replacement),
+    }
+  };
+  Ok(rule.with_external(item.external.unwrap_or(false)))
+}
+
+fn alias_items_to_pairs(items: Vec<AliasItem>) -> Vec<(String, Vec<String>)> {
+  items.into_iter().map(|alias_item| (alias_item.find, alias_item.replacements)).collect()
+}
+
+impl TryFrom<BindingResolveOptions> for rolldown::ResolveOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingResolveOptions) -> anyhow::Result<Self> {
+    Ok(Self {
+      alias: value.alias.map(alias_items_to_pairs),
+      alias_rules: value
+        .alias_rules
+        .map(|rules| rules.into_iter().map(alias_rule_item_to_rule).collect::<anyhow::Result<_>>())
+        .transpose()?,
       alias_fields: value.alias_fields,
       condition_names: value.condition_names,
       exports_fields: value.exports_fields,
@@ -36,6 +77,16 @@ impl From<BindingResolveOptions> for rolldown::ResolveOptions {
       modules: value.modules,
       symlinks: value.symlinks,
       tsconfig_filename: value.tsconfig_filename,
-    }
+      tsconfig_discovery: value.tsconfig_discovery,
+      extension_alias: value
+        .extension_alias
+        .map(|items| items.into_iter().map(|item| (item.target, item.replacements)).collect()),
+      fallback: value.fallback.map(alias_items_to_pairs),
+      roots: value.roots,
+      restrictions: value.restrictions,
+      fully_specified: value.fully_specified,
+      prefer_relative: value.prefer_relative,
+      prefer_absolute: value.prefer_absolute,
+    })
   }
 }
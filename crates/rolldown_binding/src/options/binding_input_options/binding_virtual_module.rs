@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingVirtualModule {
+  pub source: String,
+  pub module_type: Option<String>,
+}
@@ -8,12 +8,19 @@ use napi::threadsafe_function::ThreadsafeFunction;
 use napi_derive::napi;
 use serde::Deserialize;
 
-use self::{binding_input_item::BindingInputItem, binding_resolve_options::BindingResolveOptions};
+use self::{
+  binding_css_modules_options::BindingCssModulesOptions, binding_drop_options::BindingDropOptions,
+  binding_input_item::BindingInputItem, binding_resolve_options::BindingResolveOptions,
+  binding_virtual_module::BindingVirtualModule,
+};
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
 
+mod binding_css_modules_options;
+mod binding_drop_options;
 mod binding_input_item;
 mod binding_resolve_options;
+mod binding_virtual_module;
 mod treeshake;
 
 #[napi(object, object_to_js = false)]
@@ -39,7 +46,7 @@ pub struct BindingInputOptions {
     ThreadsafeFunction<(String, Option<String>, bool), bool, (String, Option<String>, bool), false>,
   >,
   pub input: Vec<BindingInputItem>,
-  // makeAbsoluteExternalsRelative?: boolean | 'ifRelativeSource';
+  pub make_absolute_externals_relative: Option<bool>,
   // /** @deprecated Use the "manualChunks" output option instead. */
   // manualChunks?: ManualChunksOption;
   // maxParallelFileOps?: number;
@@ -62,6 +69,9 @@ pub struct BindingInputOptions {
   // watch?: WatcherOptions | false;
   #[napi(ts_type = "'node' | 'browser' | 'neutral'")]
   pub platform: Option<String>,
+  pub node_polyfills: Option<HashMap<String, String>>,
+  pub http_imports: Option<String>,
+  pub virtual_modules: Option<HashMap<String, BindingVirtualModule>>,
   #[serde(skip_deserializing)]
   pub log_level: Option<BindingLogLevel>,
   #[derivative(Debug = "ignore")]
@@ -74,6 +84,11 @@ pub struct BindingInputOptions {
   pub treeshake: Option<treeshake::BindingTreeshake>,
 
   pub module_types: Option<HashMap<String, String>>,
+  pub css_modules: Option<BindingCssModulesOptions>,
+  pub asset_inline_limit: Option<u32>,
+  #[napi(ts_type = "'legacy' | 'standard' | '2023-11'")]
+  pub decorators: Option<String>,
+  pub drop: Option<BindingDropOptions>,
 }
 
 pub type BindingOnLog =
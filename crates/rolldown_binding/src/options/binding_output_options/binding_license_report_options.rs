@@ -0,0 +1,22 @@
+use rolldown::LicenseReportFormat;
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingLicenseReportOptions {
+  pub file_name: Option<String>,
+  #[napi(ts_type = "'text' | 'json'")]
+  pub format: Option<String>,
+}
+
+impl TryFrom<BindingLicenseReportOptions> for rolldown::LicenseReportOptions {
+  type Error = String;
+
+  fn try_from(value: BindingLicenseReportOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      file_name: value.file_name,
+      format: value.format.as_deref().map(LicenseReportFormat::try_from).transpose()?,
+    })
+  }
+}
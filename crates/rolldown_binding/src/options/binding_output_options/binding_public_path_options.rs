@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingPublicPathOptions {
+  pub base: Option<String>,
+  pub runtime: Option<String>,
+  pub dynamic_imports: Option<bool>,
+}
+
+impl From<BindingPublicPathOptions> for rolldown::PublicPathOptions {
+  fn from(value: BindingPublicPathOptions) -> Self {
+    Self { base: value.base, runtime: value.runtime, dynamic_imports: value.dynamic_imports }
+  }
+}
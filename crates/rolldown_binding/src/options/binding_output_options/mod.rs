@@ -1,11 +1,19 @@
 use crate::types::js_callback::MaybeAsyncJsCallback;
 
+use self::{
+  binding_license_report_options::BindingLicenseReportOptions,
+  binding_public_path_options::BindingPublicPathOptions,
+};
 use super::super::types::binding_rendered_chunk::RenderedChunk;
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
 use derivative::Derivative;
 use napi::threadsafe_function::ThreadsafeFunction;
 use napi_derive::napi;
 use serde::Deserialize;
+use std::collections::HashMap;
+
+mod binding_license_report_options;
+mod binding_public_path_options;
 
 pub type AddonOutputOption = MaybeAsyncJsCallback<RenderedChunk, Option<String>>;
 
@@ -27,6 +35,7 @@ pub struct BindingOutputOptions {
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub banner: Option<AddonOutputOption>,
   // chunkFileNames: string | ((chunkInfo: PreRenderedChunk) => string);
+  pub comments: Option<bool>,
   // compact: boolean;
   pub dir: Option<String>,
   // pub entry_file_names: String, // | ((chunkInfo: PreRenderedChunk) => string)
@@ -56,7 +65,7 @@ pub struct BindingOutputOptions {
   // namespaceToStringTag: boolean;
   // noConflict: boolean;
   // outro: () => string | Promise<string>;
-  // paths: OptionsPaths;
+  pub paths: Option<HashMap<String, String>>,
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(BindingBuiltinPlugin | BindingPluginOptions | undefined)[]")]
   pub plugins: Vec<BindingPluginOrParallelJsPluginPlaceholder>,
@@ -83,4 +92,10 @@ pub struct BindingOutputOptions {
   // validate: boolean;
   // --- Enhanced options
   // pub minify: bool,
+  #[napi(ts_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
+  pub legal_comments: Option<String>,
+  pub license_report: Option<BindingLicenseReportOptions>,
+  pub css_minify: Option<bool>,
+  pub css_targets: Option<Vec<String>>,
+  pub public_path: Option<BindingPublicPathOptions>,
 }
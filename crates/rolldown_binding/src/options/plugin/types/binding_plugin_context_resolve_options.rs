@@ -7,7 +7,7 @@ use serde::Deserialize;
 #[serde(rename_all = "camelCase")]
 #[derivative(Debug)]
 pub struct BindingPluginContextResolveOptions {
-  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call'")]
+  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call' | 'new-url' | 'worker'")]
   pub import_kind: Option<String>,
 }
 
@@ -117,4 +117,9 @@ impl BindingOutputChunk {
   pub fn name(&self) -> String {
     self.inner.name.to_string()
   }
+
+  #[napi(getter)]
+  pub fn css_files(&self) -> Vec<String> {
+    self.inner.css_files.clone()
+  }
 }
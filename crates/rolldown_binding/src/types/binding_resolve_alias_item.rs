@@ -1,5 +1,8 @@
+use derivative::Derivative;
 use serde::Deserialize;
 
+use super::js_callback::MaybeAsyncJsCallback;
+
 #[napi_derive::napi(object)]
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -7,3 +10,31 @@ pub struct AliasItem {
   pub find: String,
   pub replacements: Vec<String>,
 }
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionAliasItem {
+  pub target: String,
+  pub replacements: Vec<String>,
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Default, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct AliasRuleItem {
+  /// The exact specifier to replace, or the source of a regex if `flags` is set.
+  pub find: Option<String>,
+  /// The flags of the regex, like `i` in `/react/i`.
+  pub flags: Option<String>,
+  pub replacement: Option<String>,
+  /// Returns the new specifier, or nothing if the rule doesn't apply. Used instead of `find` and `replacement`.
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "(specifier: string, importer: Nullable<string>) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub find_fn: Option<MaybeAsyncJsCallback<(String, Option<String>), Option<String>>>,
+  pub external: Option<bool>,
+}
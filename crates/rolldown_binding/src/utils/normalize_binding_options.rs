@@ -6,7 +6,10 @@ use crate::{
   worker_manager::WorkerManager,
 };
 use napi::Either;
-use rolldown::{AddonOutputOption, BundlerOptions, IsExternal, ModuleType, OutputFormat, Platform};
+use rolldown::{
+  AddonOutputOption, BundlerOptions, DecoratorMode, HttpImports, IsExternal, LegalComments,
+  ModuleType, OutputFormat, OutputPaths, Platform, VirtualModule,
+};
 use rolldown_plugin::SharedPlugin;
 use std::path::PathBuf;
 #[cfg(not(target_family = "wasm"))]
@@ -94,6 +97,20 @@ pub fn normalize_binding_options(
     module_types = Some(tmp);
   }
 
+  let mut virtual_modules = None;
+  if let Some(raw) = input_options.virtual_modules {
+    let mut tmp = HashMap::with_capacity(raw.len());
+    for (id, module) in raw {
+      let module_type = module
+        .module_type
+        .map(|module_type| ModuleType::from_str(&module_type))
+        .transpose()
+        .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?;
+      tmp.insert(id, VirtualModule { source: module.source, module_type });
+    }
+    virtual_modules = Some(tmp);
+  }
+
   let bundler_options = BundlerOptions {
     input: Some(input_options.input.into_iter().map(Into::into).collect()),
     cwd: cwd.into(),
@@ -102,7 +119,11 @@ pub fn normalize_binding_options(
       Some(v) => v.try_into().map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
       None => rolldown::TreeshakeOptions::False,
     },
-    resolve: input_options.resolve.map(Into::into),
+    resolve: input_options
+      .resolve
+      .map(TryInto::try_into)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
     platform: input_options
       .platform
       .as_deref()
@@ -110,6 +131,9 @@ pub fn normalize_binding_options(
       .transpose()
       .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
     shim_missing_exports: input_options.shim_missing_exports,
+    node_polyfills: input_options.node_polyfills,
+    http_imports: input_options.http_imports.map(|dir| HttpImports::CacheDir(dir.into())),
+    virtual_modules,
     entry_filenames: output_options.entry_file_names,
     chunk_filenames: output_options.chunk_file_names,
     asset_filenames: output_options.asset_file_names,
@@ -119,6 +143,25 @@ pub fn normalize_binding_options(
     footer: normalize_addon_option(output_options.footer),
     sourcemap_ignore_list,
     sourcemap_path_transform,
+    legal_comments: output_options
+      .legal_comments
+      .as_deref()
+      .map(LegalComments::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    comments: output_options.comments,
+    license_report: output_options
+      .license_report
+      .map(TryInto::try_into)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    css_modules: input_options.css_modules.map(Into::into),
+    asset_inline_limit: input_options.asset_inline_limit.map(|limit| limit as usize),
+    css_minify: output_options.css_minify,
+    css_targets: output_options.css_targets,
+    public_path: output_options.public_path.map(Into::into),
+    paths: output_options.paths.map(|paths| OutputPaths::Map(paths.into_iter().collect())),
+    make_absolute_externals_relative: input_options.make_absolute_externals_relative,
     format: output_options.format.map(|format_str| match format_str.as_str() {
       "es" => OutputFormat::Esm,
       "cjs" => OutputFormat::Cjs,
@@ -126,6 +169,13 @@ pub fn normalize_binding_options(
       _ => panic!("Invalid format: {format_str}"),
     }),
     module_types,
+    decorators: input_options
+      .decorators
+      .as_deref()
+      .map(DecoratorMode::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    drop: input_options.drop.map(Into::into),
     experimental: None,
   };
 
@@ -1,7 +1,9 @@
 // cSpell:disable
+use std::path::Path;
+
 use crate::{
-  ChunkIdx, ChunkKind, FilenameTemplate, ModuleIdx, NamedImport, NormalizedBundlerOptions,
-  ResourceId, SymbolRef,
+  ChunkIdx, ChunkKind, ExternalModule, FilenameTemplate, ModuleIdx, NamedImport,
+  NormalizedBundlerOptions, ResourceId, SymbolRef,
 };
 pub mod types;
 
@@ -33,6 +35,8 @@ pub struct Chunk {
   pub imports_from_external_modules: Vec<(ModuleIdx, Vec<NamedImport>)>,
   // meaningless if the chunk is an entrypoint
   pub exports_to_other_chunks: FxHashMap<SymbolRef, Rstr>,
+  /// Whether the chunk contains stylesheets, which go to a css file next to the chunk.
+  pub has_css: bool,
 }
 
 impl Chunk {
@@ -72,12 +76,36 @@ impl Chunk {
     let importer_dir =
       self.absolute_preliminary_filename.as_ref().unwrap().as_path().parent().unwrap();
     let importee_filename = importee.absolute_preliminary_filename.as_ref().unwrap();
-    let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
+    to_relative_import_path(importee_filename.relative(importer_dir).as_path().expect_to_slash())
+  }
 
-    if import_path.starts_with('.') {
-      import_path
-    } else {
-      format!("./{import_path}")
+  /// The specifier used to import `external` from this chunk, after applying `paths` and
+  /// `make_absolute_externals_relative`.
+  pub fn import_path_for_external(
+    &self,
+    external: &ExternalModule,
+    options: &NormalizedBundlerOptions,
+  ) -> String {
+    if let Some(path) = options.paths.as_ref().and_then(|paths| paths.call(&external.name)) {
+      return path;
+    }
+    let external_path = Path::new(&external.name);
+    if options.make_absolute_externals_relative && external_path.is_absolute() {
+      let filename = self.preliminary_filename.as_ref().expect("should have preliminary filename");
+      let importer_path = options.cwd.join(&options.dir).join(filename.as_str());
+      let importer_dir = importer_path.parent().expect("chunk file should have a parent dir");
+      return to_relative_import_path(
+        external_path.relative(importer_dir).as_path().expect_to_slash(),
+      );
     }
+    external.name.clone()
+  }
+}
+
+fn to_relative_import_path(import_path: String) -> String {
+  if import_path.starts_with('.') {
+    import_path
+  } else {
+    format!("./{import_path}")
   }
 }
@@ -1,10 +1,46 @@
 use std::sync::Arc;
 
+use lightningcss::stylesheet::StyleSheet;
+use rustc_hash::FxHashMap;
+
+use crate::ModuleIdx;
+
 use super::css_module_idx::CssModuleIdx;
 
 pub struct CssModule {
   pub exec_order: u32,
-  pub source: Arc<str>,
   pub idx: CssModuleIdx,
-  pub ast: lightningcss::stylesheet::StyleSheet<'static, 'static>,
+  /// The facade module of this stylesheet in the module graph.
+  pub owner: ModuleIdx,
+  /// `@import` rules that are not bundled, such as imports of remote stylesheets or imports with media queries. They
+  /// are hoisted to the top of the emitted css file.
+  pub kept_imports: Vec<String>,
+  /// Replacements of the urls in `url()`, keyed by the original url. Urls without a replacement are kept as they are.
+  pub urls: FxHashMap<String, CssUrl>,
+  // `ast` borrows from `source` and `css_modules_pattern`, so it must be declared, and thus dropped, before them.
+  pub ast: StyleSheet<'static, 'static>,
+  pub source: Arc<str>,
+  /// The pattern of the generated class names if the stylesheet is a css module.
+  pub css_modules_pattern: Option<Arc<str>>,
+}
+
+impl std::fmt::Debug for CssModule {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CssModule")
+      .field("exec_order", &self.exec_order)
+      .field("idx", &self.idx)
+      .field("owner", &self.owner)
+      .field("kept_imports", &self.kept_imports)
+      .field("urls", &self.urls)
+      .field("css_modules_pattern", &self.css_modules_pattern)
+      .finish_non_exhaustive()
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum CssUrl {
+  /// The referenced file is inlined as a data url.
+  Inline(String),
+  /// The referenced file is emitted as an asset with the file name, which is relative to the output directory.
+  Asset(String),
 }
@@ -3,10 +3,10 @@ use std::{fmt::Debug, sync::Arc};
 use crate::side_effects::DeterminedSideEffects;
 use crate::{
   types::ast_scopes::AstScopes, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecord,
-  ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleIdx, ModuleInfo, NamedImport, ResourceId,
-  StmtInfo, StmtInfos, SymbolRef,
+  ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleIdx, ModuleInfo, NamedImport, PackageJson,
+  ResourceId, StmtInfo, StmtInfos, SymbolRef,
 };
-use crate::{IndexModules, ModuleType};
+use crate::{CssModuleIdx, IndexModules, ModuleType};
 use oxc::index::IndexVec;
 use oxc::span::Span;
 use rolldown_rstr::Rstr;
@@ -53,6 +53,11 @@ pub struct EcmaModule {
   pub dynamically_imported_ids: Vec<ResourceId>,
   pub side_effects: DeterminedSideEffects,
   pub module_type: ModuleType,
+  /// The nearest `package.json` of the module.
+  pub package_json: Option<Arc<PackageJson>>,
+  /// The stylesheet of the module if it's a css module. The module itself is a facade that imports the stylesheets
+  /// which the stylesheet `@import`s, so the css modules are ordered by the `exec_order` of their facades.
+  pub css_module_idx: Option<CssModuleIdx>,
 }
 
 impl EcmaModule {
@@ -1,6 +1,7 @@
 use crate::{AssetSource, FileNameRenderOptions, NormalizedBundlerOptions, Output, OutputAsset};
 use dashmap::{DashMap, DashSet};
 use rolldown_utils::xxhash::xxhash_base64_url;
+use rustc_hash::FxHashSet;
 use std::ffi::OsStr;
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -68,17 +69,26 @@ impl FileEmitter {
   }
 
   pub fn add_additional_files(&self, bundle: &mut Vec<Output>) {
+    // The same file might be emitted multiple times, e.g. an image referenced by multiple stylesheets.
+    let mut added_file_names = FxHashSet::default();
+    let mut assets = vec![];
     for file in &self.files {
       let (key, value) = file.pair();
       if self.emitted_files.contains(key) {
         continue;
       }
       self.emitted_files.insert(key.clone());
-      bundle.push(Output::Asset(Box::new(OutputAsset {
+      if !added_file_names.insert(value.file_name.clone()) {
+        continue;
+      }
+      assets.push(OutputAsset {
         filename: value.file_name.clone().expect("should have file name"),
         source: value.source.clone(),
-      })));
+      });
     }
+    // Files are emitted concurrently, so sort them to keep the output deterministic.
+    assets.sort_unstable_by(|a, b| a.filename.cmp(&b.filename));
+    bundle.extend(assets.into_iter().map(|asset| Output::Asset(Box::new(asset))));
   }
 }
 
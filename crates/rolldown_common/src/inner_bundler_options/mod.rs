@@ -6,13 +6,17 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer};
 use types::experimental_options::ExperimentalOptions;
 
-use crate::{ModuleType, SourceMapIgnoreList};
+use crate::{
+  CssModulesOptions, DecoratorMode, DropOptions, LegalComments, LicenseReportOptions, ModuleType,
+  PublicPathOptions, SourceMapIgnoreList,
+};
 
 use self::types::treeshake::TreeshakeOptions;
 use self::types::{
-  input_item::InputItem, is_external::IsExternal, output_format::OutputFormat,
-  output_option::AddonOutputOption, platform::Platform, resolve_options::ResolveOptions,
-  source_map_type::SourceMapType, sourcemap_path_transform::SourceMapPathTransform,
+  http_imports::HttpImports, input_item::InputItem, is_external::IsExternal,
+  output_format::OutputFormat, output_option::AddonOutputOption, output_paths::OutputPaths,
+  platform::Platform, resolve_options::ResolveOptions, source_map_type::SourceMapType,
+  sourcemap_path_transform::SourceMapPathTransform, virtual_module::VirtualModule,
 };
 
 pub mod types;
@@ -30,10 +34,32 @@ pub struct BundlerOptions {
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_external"),
-    schemars(with = "Option<Vec<String>>")
+    schemars(with = "Option<types::external_matcher::ExternalConfig>")
   )]
+  /// Imports to leave out of the bundle. Test configs accept a list of package names, which also
+  /// match their subpaths, or an object with `packages`, `regexes`, `globs`, `dependencies` and `nodeBuiltins`.
   pub external: Option<IsExternal>,
+  /// Render imports of externals that are absolute paths relative to the importing chunk. Defaults to `false`.
+  pub make_absolute_externals_relative: Option<bool>,
+  /// Defaults to `browser`. Node.js builtin modules are always external on `node`, where their imports are rendered
+  /// with the `node:` prefix. On `browser`, they are replaced by the packages in `node_polyfills`.
   pub platform: Option<Platform>,
+  /// Packages used in place of Node.js builtin modules on the `browser` platform, e.g. `{ "path": "path-browserify" }`.
+  /// Keys are the names of the builtins without the `node:` prefix.
+  pub node_polyfills: Option<HashMap<String, String>>,
+  /// `http(s):` imports are external by default. Set this to bundle them from local copies instead. Test configs accept
+  /// the cache directory, which is relative to `cwd`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_http_imports"),
+    schemars(with = "Option<PathBuf>")
+  )]
+  pub http_imports: Option<HttpImports>,
+  /// Modules provided by their source instead of files, e.g. `{ "virtual:config": { "source": "export default {}" } }`.
+  /// Imports of these ids are resolved before plugins and the file system, and the ids are kept as they are in
+  /// diagnostics. Relative imports in a virtual module refer to other virtual modules next to its id first, so `./b.js`
+  /// in `virtual:lib/a.js` is `virtual:lib/b.js`, and files relative to `cwd` otherwise.
+  pub virtual_modules: Option<HashMap<String, VirtualModule>>,
   pub shim_missing_exports: Option<bool>,
   // --- options for output
   pub entry_filenames: Option<String>,
@@ -66,6 +92,35 @@ pub struct BundlerOptions {
     schemars(skip)
   )]
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
+  /// What to do with legal comments like `/*! ... */` or `/* @license ... */`. Defaults to `none`.
+  pub legal_comments: Option<LegalComments>,
+  /// Keep block comments like JSDoc that are attached to top-level statements. Defaults to `false`.
+  pub comments: Option<bool>,
+  /// Emit a file listing the licenses of the npm packages included in the bundle.
+  pub license_report: Option<LicenseReportOptions>,
+  /// Options for css modules, i.e. stylesheets named `*.module.css`.
+  pub css_modules: Option<CssModulesOptions>,
+  /// `asset` and `dataurl` modules smaller than this size in bytes are inlined as data urls, the others are emitted
+  /// as files. Without it, `dataurl` modules are always inlined and `asset` modules are always emitted. `base64`
+  /// modules are not affected, because they export the content itself instead of a url. Files referenced by
+  /// stylesheets use a limit of `4096` if this isn't set. Imports with the `?inline` or `?url` query are always
+  /// inlined or emitted.
+  pub asset_inline_limit: Option<usize>,
+  /// Minify the emitted css files. Defaults to `false`.
+  pub css_minify: Option<bool>,
+  /// The browsers that the emitted css files should support, e.g. `["chrome87", "safari14.1"]`. Syntax like nesting and
+  /// modern color functions is lowered and vendor prefixes are added for them. Supported browsers are `android`,
+  /// `chrome`, `edge`, `firefox`, `ie`, `ios`, `opera`, `safari` and `samsung`.
+  pub css_targets: Option<Vec<String>>,
+  /// Reference emitted assets and chunks by a base url, e.g. a CDN, instead of relative paths.
+  pub public_path: Option<PublicPathOptions>,
+  /// Rewrite the ids of external modules in the rendered imports, e.g. `{ "react": "https://esm.sh/react" }`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_paths"),
+    schemars(with = "Option<HashMap<String, String>>")
+  )]
+  pub paths: Option<OutputPaths>,
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<HashMap<String, ModuleType>>,
@@ -77,6 +132,11 @@ pub struct BundlerOptions {
     schemars(with = "Option<bool>")
   )]
   pub treeshake: TreeshakeOptions,
+  // --- options for transform
+  /// Which decorators proposal `@decorator` syntax follows. Defaults to `standard`.
+  pub decorators: Option<DecoratorMode>,
+  /// Remove `console.*` calls, `debugger` statements or labeled statements before bundling.
+  pub drop: Option<DropOptions>,
   pub experimental: Option<ExperimentalOptions>,
 }
 
@@ -85,8 +145,34 @@ fn deserialize_external<'de, D>(deserializer: D) -> Result<Option<IsExternal>, D
 where
   D: Deserializer<'de>,
 {
-  let deserialized = Option::<Vec<String>>::deserialize(deserializer)?;
-  Ok(deserialized.map(IsExternal::from_vec))
+  use types::external_matcher::{ExternalConfig, ExternalMatcher};
+  let deserialized = Option::<ExternalConfig>::deserialize(deserializer)?;
+  deserialized
+    .map(|config| match config {
+      ExternalConfig::Packages(packages) => Ok(IsExternal::from_vec(packages)),
+      ExternalConfig::Matcher(config) => {
+        ExternalMatcher::try_from(config).map(IsExternal::from).map_err(serde::de::Error::custom)
+      }
+    })
+    .transpose()
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_paths<'de, D>(deserializer: D) -> Result<Option<OutputPaths>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<HashMap<String, String>>::deserialize(deserializer)?;
+  Ok(deserialized.map(|map| OutputPaths::Map(map.into_iter().collect())))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_http_imports<'de, D>(deserializer: D) -> Result<Option<HttpImports>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<PathBuf>::deserialize(deserializer)?;
+  Ok(deserialized.map(HttpImports::CacheDir))
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -0,0 +1,130 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::js_regex::HybridRegex;
+
+type AliasFn = dyn Fn(
+    &str,         // specifier
+    Option<&str>, // importer
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<String>>> + Send + 'static)>>
+  + Send
+  + Sync
+  + 'static;
+
+/// How an [AliasRule] matches and rewrites a specifier.
+pub enum AliasFind {
+  /// Replaces the specifier with `replacement` if it's equal to `find`.
+  Exact { find: String, replacement: String },
+  /// Replaces the match of the regex with `replacement`, which can refer to capture groups like `$1`.
+  Regex { regex: HybridRegex, replacement: String },
+  /// Returns the new specifier, or `None` if the rule doesn't apply.
+  Fn(Box<AliasFn>),
+}
+
+impl Debug for AliasFind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Exact { find, replacement } => f
+        .debug_struct("AliasFind::Exact")
+        .field("find", find)
+        .field("replacement", replacement)
+        .finish(),
+      Self::Regex { regex, replacement } => f
+        .debug_struct("AliasFind::Regex")
+        .field("regex", regex)
+        .field("replacement", replacement)
+        .finish(),
+      Self::Fn(_) => write!(f, "AliasFind::Fn(...)"),
+    }
+  }
+}
+
+/// An alias that rewrites the whole specifier before it's resolved. Unlike `ResolveOptions::alias`, which only
+/// replaces prefixes, the specifier is matched exactly, by a regex or by a function. Rewritten specifiers starting
+/// with `./` or `../` are relative to `cwd`.
+#[derive(Debug)]
+pub struct AliasRule {
+  pub find: AliasFind,
+  /// Treat the rewritten specifier as an external module instead of resolving it.
+  pub external: bool,
+}
+
+impl AliasRule {
+  pub fn exact(find: impl Into<String>, replacement: impl Into<String>) -> Self {
+    Self {
+      find: AliasFind::Exact { find: find.into(), replacement: replacement.into() },
+      external: false,
+    }
+  }
+
+  pub fn regex(regex: HybridRegex, replacement: impl Into<String>) -> Self {
+    Self { find: AliasFind::Regex { regex, replacement: replacement.into() }, external: false }
+  }
+
+  pub fn from_fn<F>(f: F) -> Self
+  where
+    F: Fn(
+        &str,         // specifier
+        Option<&str>, // importer
+      ) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<String>>> + Send + 'static)>>
+      + Send
+      + Sync
+      + 'static,
+  {
+    Self { find: AliasFind::Fn(Box::new(f)), external: false }
+  }
+
+  #[must_use]
+  pub fn with_external(mut self, external: bool) -> Self {
+    self.external = external;
+    self
+  }
+
+  /// Returns the rewritten specifier, or `None` if the rule doesn't apply to `specifier`.
+  pub async fn apply(
+    &self,
+    specifier: &str,
+    importer: Option<&str>,
+  ) -> anyhow::Result<Option<String>> {
+    match &self.find {
+      AliasFind::Exact { find, replacement } => {
+        Ok((find == specifier).then(|| replacement.clone()))
+      }
+      AliasFind::Regex { regex, replacement } => Ok(regex.replace(specifier, replacement)),
+      AliasFind::Fn(f) => f(specifier, importer).await,
+    }
+  }
+}
+
+/// The serialized form of an exact or regex [AliasRule].
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AliasRuleConfig {
+  /// The exact specifier to replace, or the source of a regex if `flags` is set.
+  pub find: String,
+  /// The flags of the regex, like `i` in `/react/i`.
+  pub flags: Option<String>,
+  pub replacement: String,
+  #[serde(default)]
+  pub external: bool,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl TryFrom<AliasRuleConfig> for AliasRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: AliasRuleConfig) -> Result<Self, Self::Error> {
+    let rule = match value.flags {
+      Some(flags) => Self::regex(HybridRegex::with_flags(&value.find, &flags)?, value.replacement),
+      None => Self::exact(value.find, value.replacement),
+    };
+    Ok(rule.with_external(value.external))
+  }
+}
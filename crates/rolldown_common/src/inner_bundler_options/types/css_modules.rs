@@ -0,0 +1,28 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for stylesheets whose file name ends with `.module.css`.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CssModulesOptions {
+  /// The pattern of the generated class names. Supports `[hash]`, `[name]` and `[local]`. Defaults to `[hash]_[local]`.
+  pub pattern: Option<String>,
+  /// Whether to scope dashed identifiers, e.g. custom properties. Defaults to `false`.
+  pub dashed_idents: Option<bool>,
+}
+
+impl CssModulesOptions {
+  pub fn pattern(&self) -> &str {
+    self.pattern.as_deref().unwrap_or("[hash]_[local]")
+  }
+
+  pub fn dashed_idents(&self) -> bool {
+    self.dashed_idents.unwrap_or(false)
+  }
+}
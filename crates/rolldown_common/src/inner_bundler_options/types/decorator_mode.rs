@@ -0,0 +1,37 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Decides which decorators proposal is used to lower `@decorator` syntax.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum DecoratorMode {
+  /// TypeScript's `experimentalDecorators`.
+  Legacy,
+  /// The TC39 decorators proposal, version `2023-11`.
+  #[default]
+  Standard,
+}
+
+impl DecoratorMode {
+  pub fn is_legacy(self) -> bool {
+    matches!(self, Self::Legacy)
+  }
+}
+
+impl TryFrom<&str> for DecoratorMode {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "legacy" => Ok(Self::Legacy),
+      "standard" | "2023-11" => Ok(Self::Standard),
+      _ => Err(format!("Unknown decorator mode: {value:?}")),
+    }
+  }
+}
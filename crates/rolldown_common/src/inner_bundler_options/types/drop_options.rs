@@ -0,0 +1,40 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Code to remove from modules before they are scanned.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct DropOptions {
+  /// Remove calls to `console.*`. Their arguments are not evaluated anymore.
+  pub console: Option<bool>,
+  /// Remove `debugger` statements.
+  pub debugger: Option<bool>,
+  /// Remove statements labeled with any of these names, such as `DEV: { ... }`.
+  pub labels: Option<Vec<String>>,
+}
+
+impl DropOptions {
+  pub fn is_console_dropped(&self) -> bool {
+    self.console.unwrap_or(false)
+  }
+
+  pub fn is_debugger_dropped(&self) -> bool {
+    self.debugger.unwrap_or(false)
+  }
+
+  pub fn is_label_dropped(&self, label: &str) -> bool {
+    self.labels.as_ref().is_some_and(|labels| labels.iter().any(|name| name == label))
+  }
+
+  pub fn is_empty(&self) -> bool {
+    !self.is_console_dropped()
+      && !self.is_debugger_dropped()
+      && self.labels.as_ref().map_or(true, Vec::is_empty)
+  }
+}
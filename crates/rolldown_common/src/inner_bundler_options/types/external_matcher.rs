@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use rolldown_fs::FileSystem;
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use super::platform::is_node_builtin;
+use crate::{js_regex::HybridRegex, side_effects::glob_match_with_normalized_pattern};
+
+/// Declarative rules deciding whether an import is external.
+#[derive(Debug, Default)]
+pub struct ExternalMatcher {
+  /// Package names that are external together with their subpaths, so `lodash` also matches `lodash/merge`.
+  pub packages: Vec<String>,
+  /// Regexes tested against both the import specifier and the resolved id.
+  pub regexes: Vec<HybridRegex>,
+  /// Globs tested against both the import specifier and the resolved id, such as `**/vendor/**`.
+  pub globs: Vec<String>,
+  /// Whether `dependencies` and `peerDependencies` of the root `package.json` are external.
+  pub dependencies: bool,
+  /// Whether node builtin modules such as `fs` or `node:fs` are external.
+  pub node_builtins: bool,
+}
+
+impl ExternalMatcher {
+  pub fn from_packages(packages: Vec<String>) -> Self {
+    Self { packages, ..Default::default() }
+  }
+
+  /// Reads the `package.json` under `cwd` and adds its `dependencies` and `peerDependencies` to
+  /// the external packages. A missing or malformed `package.json` adds nothing.
+  pub fn load_dependencies(&mut self, cwd: &Path, fs: &dyn FileSystem) {
+    if !self.dependencies {
+      return;
+    }
+    let Ok(content) = fs.read_to_string(&cwd.join("package.json")) else {
+      return;
+    };
+    let Ok(package_json) = serde_json::from_str::<serde_json::Value>(&content) else {
+      return;
+    };
+    for field in ["dependencies", "peerDependencies"] {
+      if let Some(deps) = package_json.get(field).and_then(serde_json::Value::as_object) {
+        for name in deps.keys() {
+          if !self.packages.contains(name) {
+            self.packages.push(name.clone());
+          }
+        }
+      }
+    }
+  }
+
+  pub fn matches(&self, id: &str, is_resolved: bool) -> bool {
+    if self.regexes.iter().any(|regex| regex.matches(id))
+      || self.globs.iter().any(|glob| glob_match_with_normalized_pattern(glob, id))
+    {
+      return true;
+    }
+    if is_resolved {
+      return false;
+    }
+    self.packages.iter().any(|package| is_package_or_subpath(id, package))
+      || (self.node_builtins && is_node_builtin(id))
+  }
+}
+
+fn is_package_or_subpath(specifier: &str, package: &str) -> bool {
+  specifier.strip_prefix(package).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// The serialized form of [ExternalMatcher], where regexes are plain sources.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExternalMatcherConfig {
+  #[serde(default)]
+  pub packages: Vec<String>,
+  #[serde(default)]
+  pub regexes: Vec<String>,
+  #[serde(default)]
+  pub globs: Vec<String>,
+  #[serde(default)]
+  pub dependencies: bool,
+  #[serde(default)]
+  pub node_builtins: bool,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl TryFrom<ExternalMatcherConfig> for ExternalMatcher {
+  type Error = anyhow::Error;
+
+  fn try_from(value: ExternalMatcherConfig) -> Result<Self, Self::Error> {
+    Ok(Self {
+      packages: value.packages,
+      regexes: value
+        .regexes
+        .iter()
+        .map(|source| HybridRegex::new(source))
+        .collect::<Result<_, _>>()?,
+      globs: value.globs,
+      dependencies: value.dependencies,
+      node_builtins: value.node_builtins,
+    })
+  }
+}
+
+/// `external` in test configs accepts either a list of package names or an [ExternalMatcherConfig].
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ExternalConfig {
+  Packages(Vec<String>),
+  Matcher(ExternalMatcherConfig),
+}
@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+use std::path::{Component, Path, PathBuf};
+
+type HttpImportsFn = dyn Fn(&str) -> Option<PathBuf> + Send + Sync;
+
+/// Bundles `http(s):` imports from local copies instead of leaving them external, e.g. for offline and reproducible
+/// builds. Imports without a local copy stay external.
+pub enum HttpImports {
+  /// `https://example.com/lib/mod.js` is loaded from `<dir>/example.com/lib/mod.js`. The query and the hash of the
+  /// url are ignored.
+  CacheDir(PathBuf),
+  /// Returns the local copy of the url, or `None` to keep it external.
+  Fn(Box<HttpImportsFn>),
+}
+
+impl Debug for HttpImports {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::CacheDir(dir) => f.debug_tuple("HttpImports::CacheDir").field(dir).finish(),
+      Self::Fn(_) => write!(f, "HttpImports::Fn(...)"),
+    }
+  }
+}
+
+impl HttpImports {
+  pub fn from_fn<F>(f: F) -> Self
+  where
+    F: Fn(&str) -> Option<PathBuf> + Send + Sync + 'static,
+  {
+    Self::Fn(Box::new(f))
+  }
+
+  /// Returns the path where the local copy of `url` is expected. It might not exist.
+  pub fn local_path(&self, url: &str) -> Option<PathBuf> {
+    match self {
+      Self::CacheDir(dir) => {
+        let rest =
+          ["https://", "http://", "//"].iter().find_map(|scheme| url.strip_prefix(scheme))?;
+        let rest = &rest[..rest.find(['?', '#']).unwrap_or(rest.len())];
+        let relative = Path::new(rest);
+        // Urls like `https://example.com/../secret` must not escape the cache directory.
+        relative.components().all(|c| matches!(c, Component::Normal(_))).then(|| dir.join(relative))
+      }
+      Self::Fn(f) => f(url),
+    }
+  }
+}
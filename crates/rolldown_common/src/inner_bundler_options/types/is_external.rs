@@ -1,8 +1,12 @@
 use std::fmt::Debug;
 use std::future::Future;
-use std::ops::Deref;
+use std::path::Path;
 use std::pin::Pin;
 
+use rolldown_fs::FileSystem;
+
+use super::external_matcher::ExternalMatcher;
+
 type Inner = dyn Fn(
     &str,         // specifier
     Option<&str>, // importer
@@ -12,14 +16,9 @@ type Inner = dyn Fn(
   + Sync
   + 'static;
 
-pub struct IsExternal(Box<Inner>);
-
-impl Deref for IsExternal {
-  type Target = Inner;
-
-  fn deref(&self) -> &Self::Target {
-    &*self.0
-  }
+pub enum IsExternal {
+  Fn(Box<Inner>),
+  Matcher(ExternalMatcher),
 }
 
 impl IsExternal {
@@ -34,14 +33,46 @@ impl IsExternal {
       + Sync
       + 'static,
   {
-    Self(Box::new(f))
+    Self::Fn(Box::new(f))
   }
 
+  /// Each item is a package name that is external together with its subpaths.
   pub fn from_vec(value: Vec<String>) -> Self {
-    Self::from_closure(move |source, _, _| {
-      let result = value.iter().any(|item| item == source);
-      Box::pin(async move { Ok(result) })
-    })
+    Self::Matcher(ExternalMatcher::from_packages(value))
+  }
+
+  /// `id` is the import specifier if `is_resolved` is `false`, otherwise the resolved id.
+  pub async fn call(
+    &self,
+    id: &str,
+    importer: Option<&str>,
+    is_resolved: bool,
+  ) -> anyhow::Result<bool> {
+    match self {
+      Self::Fn(f) => f(id, importer, is_resolved).await,
+      Self::Matcher(matcher) => Ok(matcher.matches(id, is_resolved)),
+    }
+  }
+
+  /// Checks a module again after it's resolved. Functions are still called with the import `specifier`, while
+  /// matchers test `resolved_id`, so their regexes and globs can match absolute paths.
+  pub async fn call_resolved(
+    &self,
+    specifier: &str,
+    resolved_id: &str,
+    importer: Option<&str>,
+  ) -> anyhow::Result<bool> {
+    match self {
+      Self::Fn(f) => f(specifier, importer, true).await,
+      Self::Matcher(matcher) => Ok(matcher.matches(resolved_id, true)),
+    }
+  }
+
+  /// Resolves rules that depend on the project root, such as dependencies of the root `package.json`.
+  pub fn load_dependencies(&mut self, cwd: &Path, fs: &dyn FileSystem) {
+    if let Self::Matcher(matcher) = self {
+      matcher.load_dependencies(cwd, fs);
+    }
   }
 }
 
@@ -51,8 +82,17 @@ impl From<Vec<String>> for IsExternal {
   }
 }
 
+impl From<ExternalMatcher> for IsExternal {
+  fn from(value: ExternalMatcher) -> Self {
+    IsExternal::Matcher(value)
+  }
+}
+
 impl Debug for IsExternal {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "IsExternal(...)")
+    match self {
+      Self::Fn(_) => write!(f, "IsExternal(...)"),
+      Self::Matcher(matcher) => f.debug_tuple("IsExternal").field(matcher).finish(),
+    }
   }
 }
@@ -0,0 +1,52 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Decides what to do with legal comments, which are comments starting with `/*!` or `//!`, or containing `@license`
+/// or `@preserve`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LegalComments {
+  /// Drop all legal comments.
+  #[default]
+  None,
+  /// Keep legal comments where they are, before the top-level statement that follows them.
+  Inline,
+  /// Move legal comments to the end of the chunk.
+  Eof,
+  /// Move legal comments to a `[chunk].LEGAL.txt` file and link to it from the chunk with a comment.
+  Linked,
+  /// Move legal comments to a `[chunk].LEGAL.txt` file without linking to it.
+  External,
+}
+
+impl LegalComments {
+  pub fn is_none(self) -> bool {
+    matches!(self, Self::None)
+  }
+
+  /// Whether the legal comments are written to a separate `.LEGAL.txt` file.
+  pub fn is_external_file(self) -> bool {
+    matches!(self, Self::Linked | Self::External)
+  }
+}
+
+impl TryFrom<&str> for LegalComments {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "none" => Ok(Self::None),
+      "inline" => Ok(Self::Inline),
+      "eof" => Ok(Self::Eof),
+      "linked" => Ok(Self::Linked),
+      "external" => Ok(Self::External),
+      _ => Err(format!("Unknown legal comments option: {value:?}")),
+    }
+  }
+}
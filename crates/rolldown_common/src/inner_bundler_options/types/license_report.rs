@@ -0,0 +1,54 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Emit a file listing the third-party packages included in the bundle, along with their licenses.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct LicenseReportOptions {
+  /// Defaults to `THIRD-PARTY-LICENSES.txt` or `THIRD-PARTY-LICENSES.json`, depending on `format`.
+  pub file_name: Option<String>,
+  pub format: Option<LicenseReportFormat>,
+}
+
+impl LicenseReportOptions {
+  pub fn format(&self) -> LicenseReportFormat {
+    self.format.unwrap_or_default()
+  }
+
+  pub fn file_name(&self) -> &str {
+    self.file_name.as_deref().unwrap_or(match self.format() {
+      LicenseReportFormat::Text => "THIRD-PARTY-LICENSES.txt",
+      LicenseReportFormat::Json => "THIRD-PARTY-LICENSES.json",
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LicenseReportFormat {
+  #[default]
+  Text,
+  Json,
+}
+
+impl TryFrom<&str> for LicenseReportFormat {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "text" => Ok(Self::Text),
+      "json" => Ok(Self::Json),
+      _ => Err(format!("Unknown license report format: {value:?}")),
+    }
+  }
+}
@@ -1,14 +1,25 @@
+pub mod alias_rule;
+pub mod css_modules;
+pub mod decorator_mode;
+pub mod drop_options;
 pub mod experimental_options;
+pub mod external_matcher;
 pub mod filename_template;
+pub mod http_imports;
 pub mod input_item;
 pub mod is_external;
+pub mod legal_comments;
+pub mod license_report;
 pub mod module_type;
 pub mod normalized_bundler_options;
 pub mod output_format;
 pub mod output_option;
+pub mod output_paths;
 pub mod platform;
+pub mod public_path;
 pub mod resolve_options;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
 pub mod treeshake;
+pub mod virtual_module;
@@ -10,7 +10,7 @@ use serde::Deserialize;
   derive(Deserialize, JsonSchema),
   serde(rename_all = "camelCase", deny_unknown_fields)
 )]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Js,
   Jsx,
@@ -22,6 +22,9 @@ pub enum ModuleType {
   Dataurl,
   Binary,
   Empty,
+  Css,
+  /// Copy the file to the output directory and export its url.
+  Asset,
 }
 
 impl FromStr for ModuleType {
@@ -39,6 +42,8 @@ impl FromStr for ModuleType {
       "dataurl" => Ok(Self::Dataurl),
       "binary" => Ok(Self::Binary),
       "empty" => Ok(Self::Empty),
+      "css" => Ok(Self::Css),
+      "asset" => Ok(Self::Asset),
       _ => Err(format!("Unknown module type: {s}")),
     }
   }
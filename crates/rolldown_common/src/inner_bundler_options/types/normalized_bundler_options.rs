@@ -5,28 +5,42 @@ use std::path::PathBuf;
 
 use rustc_hash::FxHashMap;
 
-use crate::{InputItem, ModuleType};
+use crate::{
+  CssModulesOptions, DecoratorMode, DropOptions, InputItem, LegalComments, LicenseReportOptions,
+  ModuleType, PublicPathOptions,
+};
 
 use super::experimental_options::ExperimentalOptions;
 use super::treeshake::TreeshakeOptions;
 use super::{
-  filename_template::FilenameTemplate, is_external::IsExternal, output_format::OutputFormat,
-  output_option::AddonOutputOption, platform::Platform, source_map_type::SourceMapType,
-  sourcemap_ignore_list::SourceMapIgnoreList, sourcemap_path_transform::SourceMapPathTransform,
+  filename_template::FilenameTemplate, http_imports::HttpImports, is_external::IsExternal,
+  output_format::OutputFormat, output_option::AddonOutputOption, output_paths::OutputPaths,
+  platform::Platform, source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
+  sourcemap_path_transform::SourceMapPathTransform, virtual_module::VirtualModule,
 };
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct NormalizedBundlerOptions {
   // --- Input
   pub input: Vec<InputItem>,
   pub cwd: PathBuf,
   pub external: Option<IsExternal>,
+  pub make_absolute_externals_relative: bool,
   /// corresponding to `false | NormalizedTreeshakeOption`
   pub treeshake: TreeshakeOptions,
   pub platform: Platform,
+  /// The key is the name of a Node.js builtin module without the `node:` prefix.
+  pub node_polyfills: FxHashMap<String, String>,
+  /// A relative cache directory is already joined with `cwd`.
+  pub http_imports: Option<HttpImports>,
+  /// The key is the id of the virtual module.
+  pub virtual_modules: FxHashMap<String, VirtualModule>,
   pub shim_missing_exports: bool,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
   pub module_types: FxHashMap<String, ModuleType>,
+  pub decorators: DecoratorMode,
+  pub drop: DropOptions,
   // --- Output
   pub entry_filenames: FilenameTemplate,
   pub chunk_filenames: FilenameTemplate,
@@ -38,5 +52,14 @@ pub struct NormalizedBundlerOptions {
   pub footer: Option<AddonOutputOption>,
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
+  pub legal_comments: LegalComments,
+  pub comments: bool,
+  pub license_report: Option<LicenseReportOptions>,
+  pub css_modules: CssModulesOptions,
+  pub asset_inline_limit: Option<usize>,
+  pub css_minify: bool,
+  pub css_targets: Option<Vec<String>>,
+  pub public_path: PublicPathOptions,
+  pub paths: Option<OutputPaths>,
   pub experimental: ExperimentalOptions,
 }
@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+use rustc_hash::FxHashMap;
+
+type OutputPathsFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Rewrites the ids of external modules in the rendered imports, e.g. to point them to a CDN.
+pub enum OutputPaths {
+  Map(FxHashMap<String, String>),
+  Fn(Box<OutputPathsFn>),
+}
+
+impl Debug for OutputPaths {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Map(map) => f.debug_tuple("OutputPaths::Map").field(map).finish(),
+      Self::Fn(_) => write!(f, "OutputPaths::Fn(...)"),
+    }
+  }
+}
+
+impl OutputPaths {
+  pub fn from_fn<F>(f: F) -> Self
+  where
+    F: Fn(&str) -> Option<String> + Send + Sync + 'static,
+  {
+    Self::Fn(Box::new(f))
+  }
+
+  /// Returns `None` if `id` should be rendered as it is.
+  pub fn call(&self, id: &str) -> Option<String> {
+    match self {
+      Self::Map(map) => map.get(id).cloned(),
+      Self::Fn(f) => f(id),
+    }
+  }
+}
+
+impl From<FxHashMap<String, String>> for OutputPaths {
+  fn from(value: FxHashMap<String, String>) -> Self {
+    Self::Map(value)
+  }
+}
@@ -25,3 +25,17 @@ impl TryFrom<&str> for Platform {
     }
   }
 }
+
+/// Whether `specifier` is a Node.js builtin module, such as `fs` or `node:fs`.
+pub fn is_node_builtin(specifier: &str) -> bool {
+  match specifier.strip_prefix("node:") {
+    Some(name) => {
+      oxc_resolver::NODEJS_BUILTINS.binary_search(&name).is_ok()
+        || NODE_PREFIX_ONLY_BUILTINS.contains(&name)
+    }
+    None => oxc_resolver::NODEJS_BUILTINS.binary_search(&specifier).is_ok(),
+  }
+}
+
+/// Builtin modules that can only be imported with the `node:` prefix.
+const NODE_PREFIX_ONLY_BUILTINS: &[&str] = &["sea", "sqlite", "test", "test/reporters"];
@@ -0,0 +1,42 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Where emitted assets and chunks are served from. Without it, they are referenced relative to the importing chunk.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct PublicPathOptions {
+  /// The base url that file names are appended to, e.g. `https://cdn.example.com/`.
+  pub base: Option<String>,
+  /// A JavaScript expression evaluated at runtime that file names are appended to, e.g. `window.__CDN__`. It takes
+  /// precedence over `base` in JavaScript, while stylesheets keep using `base`.
+  pub runtime: Option<String>,
+  /// Also apply the public path to the specifiers of dynamic `import()`. Defaults to `false`.
+  pub dynamic_imports: Option<bool>,
+}
+
+impl PublicPathOptions {
+  /// The url of a file relative to the output directory with `base` prepended.
+  pub fn static_url_for(&self, filename: &str) -> Option<String> {
+    self.base.as_ref().map(|base| {
+      if base.is_empty() || base.ends_with('/') {
+        format!("{base}{filename}")
+      } else {
+        format!("{base}/{filename}")
+      }
+    })
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.base.is_none() && self.runtime.is_none()
+  }
+
+  pub fn applies_to_dynamic_imports(&self) -> bool {
+    !self.is_empty() && self.dynamic_imports.unwrap_or(false)
+  }
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+
+use super::alias_rule::AliasRule;
 
 /// A simple wrapper around `oxc_resolver::ResolveOptions` to make it easier to use in the `rolldown_resolver` crate.
 /// See [oxc_resolver::ResolveOptions](https://docs.rs/oxc_resolver/latest/oxc_resolver/struct.ResolveOptions.html) for more information.
@@ -13,6 +15,14 @@ use serde::Deserialize;
 )]
 pub struct ResolveOptions {
   pub alias: Option<Vec<(String, Vec<String>)>>,
+  /// Aliases matched by a regex or a function, applied before `alias` and the rest of the resolution. The first
+  /// matching rule wins.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_alias_rules"),
+    schemars(with = "Option<Vec<super::alias_rule::AliasRuleConfig>>")
+  )]
+  pub alias_rules: Option<Vec<AliasRule>>,
   pub alias_fields: Option<Vec<Vec<String>>>,
   pub condition_names: Option<Vec<String>>,
   pub exports_fields: Option<Vec<Vec<String>>>,
@@ -22,4 +32,97 @@ pub struct ResolveOptions {
   pub modules: Option<Vec<String>>,
   pub symlinks: Option<bool>,
   pub tsconfig_filename: Option<String>,
+  /// Use the nearest `tsconfig.json` of each importer within `cwd`, including its project references, to resolve
+  /// `paths`. `tsconfig_filename` is still used for importers without one. Defaults to `true` unless
+  /// `tsconfig_filename` is set.
+  pub tsconfig_discovery: Option<bool>,
+  /// Extensions to try instead of the one in the specifier, e.g. `[".js", [".ts", ".js"]]` resolves `./foo.js` to
+  /// `./foo.ts` if it exists.
+  pub extension_alias: Option<Vec<(String, Vec<String>)>>,
+  /// Same as `alias`, but only used when the normal resolution fails.
+  pub fallback: Option<Vec<(String, Vec<String>)>>,
+  /// Directories to resolve server-relative urls like `/foo` in. Relative paths are resolved against `cwd`.
+  pub roots: Option<Vec<String>>,
+  /// Resolved paths must be inside one of these directories. Relative paths are resolved against `cwd`.
+  pub restrictions: Option<Vec<String>>,
+  /// Require relative specifiers to include the file extension, like node does for esm. Defaults to `false`.
+  pub fully_specified: Option<bool>,
+  /// Try to resolve bare specifiers like `foo` as `./foo` first. Defaults to `false`.
+  pub prefer_relative: Option<bool>,
+  /// Try to resolve server-relative urls as absolute paths before looking in `roots`. Defaults to `false`.
+  pub prefer_absolute: Option<bool>,
+}
+
+impl ResolveOptions {
+  /// Returns the name of each invalid option together with the reason.
+  pub fn validate(&self) -> Vec<(&'static str, String)> {
+    let mut errors = vec![];
+
+    let invalid_extensions = self
+      .extensions
+      .iter()
+      .flatten()
+      .filter(|ext| !ext.is_empty() && !ext.starts_with('.'))
+      .map(|ext| format!("{ext:?}"))
+      .collect::<Vec<_>>();
+    if !invalid_extensions.is_empty() {
+      errors.push((
+        "resolve.extensions",
+        format!(
+          "extensions should be empty or start with a `.`, but got {}",
+          invalid_extensions.join(", ")
+        ),
+      ));
+    }
+
+    for (ext, aliases) in self.extension_alias.iter().flatten() {
+      if aliases.is_empty() {
+        errors.push(("resolve.extensionAlias", format!("{ext:?} should have at least one alias")));
+      } else if let Some(invalid) =
+        std::iter::once(ext).chain(aliases).find(|ext| !ext.starts_with('.'))
+      {
+        errors.push((
+          "resolve.extensionAlias",
+          format!("extensions should start with a `.`, but got {invalid:?}"),
+        ));
+      }
+    }
+
+    for (name, replacements) in self.fallback.iter().flatten() {
+      if name.is_empty() {
+        errors
+          .push(("resolve.fallback", "the name to fall back for should not be empty".to_string()));
+      } else if replacements.is_empty() {
+        errors.push(("resolve.fallback", format!("{name:?} should have at least one fallback")));
+      }
+    }
+
+    if self.prefer_relative.unwrap_or(false) && self.prefer_absolute.unwrap_or(false) {
+      errors.push((
+        "resolve.preferAbsolute",
+        "`preferAbsolute` and `preferRelative` can't be enabled at the same time".to_string(),
+      ));
+    }
+
+    if self.prefer_absolute.unwrap_or(false) && self.roots.as_ref().map_or(true, Vec::is_empty) {
+      errors.push((
+        "resolve.preferAbsolute",
+        "`preferAbsolute` only has an effect when `roots` is set".to_string(),
+      ));
+    }
+
+    errors
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_alias_rules<'de, D>(deserializer: D) -> Result<Option<Vec<AliasRule>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<Vec<super::alias_rule::AliasRuleConfig>>::deserialize(deserializer)?;
+  deserialized
+    .map(|rules| rules.into_iter().map(AliasRule::try_from).collect::<Result<Vec<_>, _>>())
+    .transpose()
+    .map_err(serde::de::Error::custom)
 }
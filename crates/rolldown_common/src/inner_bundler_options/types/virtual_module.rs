@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use super::module_type::ModuleType;
+
+/// A module whose source is provided in the options instead of being read from the file system.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct VirtualModule {
+  pub source: String,
+  /// Defaults to the module type of the extension of the id, or `js` if there's none.
+  pub module_type: Option<ModuleType>,
+}
+
+impl VirtualModule {
+  pub fn new(source: impl Into<String>) -> Self {
+    Self { source: source.into(), module_type: None }
+  }
+
+  #[must_use]
+  pub fn with_module_type(mut self, module_type: ModuleType) -> Self {
+    self.module_type = Some(module_type);
+    self
+  }
+}
@@ -12,19 +12,30 @@ mod types;
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     types::{
+      alias_rule::{AliasFind, AliasRule},
+      css_modules::CssModulesOptions,
+      decorator_mode::DecoratorMode,
+      drop_options::DropOptions,
+      external_matcher::ExternalMatcher,
       filename_template::{FileNameRenderOptions, FilenameTemplate},
+      http_imports::HttpImports,
       input_item::InputItem,
       is_external::IsExternal,
+      legal_comments::LegalComments,
+      license_report::{LicenseReportFormat, LicenseReportOptions},
       module_type::ModuleType,
       normalized_bundler_options::NormalizedBundlerOptions,
       output_format::OutputFormat,
       output_option::{AddonFunction, AddonOutputOption},
-      platform::Platform,
+      output_paths::OutputPaths,
+      platform::{is_node_builtin, Platform},
+      public_path::PublicPathOptions,
       resolve_options::ResolveOptions,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
       treeshake::{InnerOptions, ModuleSideEffects, TreeshakeOptions},
+      virtual_module::VirtualModule,
     },
     BundlerOptions,
   };
@@ -39,14 +50,16 @@ pub use crate::{
     },
     Chunk,
   },
-  css::{css_module::CssModule, css_module_idx::CssModuleIdx},
+  css::{
+    css_module::{CssModule, CssUrl},
+    css_module_idx::CssModuleIdx,
+  },
   ecmascript::{ecma_module::EcmaModule, module_idx::ModuleIdx},
   file_emitter::{EmittedAsset, FileEmitter, SharedFileEmitter},
   module::external_module::ExternalModule,
   module::Module,
   types::asset_source::AssetSource,
   types::ast_scopes::AstScopes,
-  types::bundler_file_system::BundlerFileSystem,
   types::chunk_idx::ChunkIdx,
   types::chunk_kind::ChunkKind,
   types::entry_point::{EntryPoint, EntryPointKind},
@@ -73,6 +86,7 @@ pub use crate::{
   types::side_effects,
   types::stmt_info::{DebugStmtInfoForTreeShaking, StmtInfo, StmtInfoIdx, StmtInfos},
   types::symbol_ref::{MemberExprRef, SymbolOrMemberExprRef, SymbolRef},
+  types::third_party_license::ThirdPartyLicense,
   types::wrap_kind::WrapKind,
 };
 pub use bundler_options::*;
@@ -13,10 +13,18 @@ pub struct EntryPoint {
 pub enum EntryPointKind {
   UserDefined,
   DynamicImport,
+  /// A script referenced by `new URL('./foo', import.meta.url)`
+  NewUrl,
+  /// A script started by `new Worker(...)` or `new SharedWorker(...)`
+  Worker,
 }
 
 impl EntryPointKind {
   pub fn is_user_defined(&self) -> bool {
     matches!(self, EntryPointKind::UserDefined)
   }
+
+  pub fn is_worker(&self) -> bool {
+    matches!(self, EntryPointKind::Worker)
+  }
 }
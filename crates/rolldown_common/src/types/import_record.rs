@@ -13,6 +13,10 @@ pub enum ImportKind {
   Import,
   DynamicImport,
   Require,
+  /// `new URL('./foo', import.meta.url)`
+  NewUrl,
+  /// `new Worker(new URL('./foo', import.meta.url))` or `new SharedWorker(...)`
+  Worker,
 }
 
 impl ImportKind {
@@ -29,6 +33,8 @@ impl TryFrom<&str> for ImportKind {
       "import" => Ok(Self::Import),
       "dynamic-import" => Ok(Self::DynamicImport),
       "require-call" => Ok(Self::Require),
+      "new-url" => Ok(Self::NewUrl),
+      "worker" => Ok(Self::Worker),
       _ => Err(format!("Invalid import kind: {value:?}")),
     }
   }
@@ -40,6 +46,8 @@ impl Display for ImportKind {
       Self::Import => write!(f, "import-statement"),
       Self::DynamicImport => write!(f, "dynamic-import"),
       Self::Require => write!(f, "require-call"),
+      Self::NewUrl => write!(f, "new-url"),
+      Self::Worker => write!(f, "worker"),
     }
   }
 }
@@ -15,10 +15,82 @@ impl HybridRegex {
     }
   }
 
+  /// Like [HybridRegex::new], but with the flags of a JavaScript regex, such as `i` in `/react/i`. Flags that make no
+  /// sense for matching a single string, like `g` and `y`, are rejected.
+  pub fn with_flags(source: &str, flags: &str) -> anyhow::Result<Self> {
+    if let Some(flag) = flags.chars().find(|flag| !"dimsuv".contains(*flag)) {
+      anyhow::bail!("Unsupported flag `{flag}` in regex /{source}/{flags}");
+    }
+    // The `v` flag changes the syntax of character classes, which only regress understands.
+    let optimized = if flags.contains('v') {
+      None
+    } else {
+      regex::RegexBuilder::new(source)
+        .case_insensitive(flags.contains('i'))
+        .multi_line(flags.contains('m'))
+        .dot_matches_new_line(flags.contains('s'))
+        .build()
+        .ok()
+    };
+    match optimized {
+      Some(reg) => Ok(HybridRegex::Optimize(reg)),
+      None => regress::Regex::with_flags(source, flags)
+        .map(HybridRegex::Ecma)
+        .map_err(anyhow::Error::from),
+    }
+  }
+
   pub fn matches(&self, text: &str) -> bool {
     match self {
       HybridRegex::Optimize(reg) => reg.is_match(text),
       HybridRegex::Ecma(reg) => reg.find(text).is_some(),
     }
   }
+
+  /// Replaces the first match in `text` like `String.prototype.replace` in JavaScript, where `$1`, `$&` and `$$`
+  /// in `replacement` refer to a capture group, the whole match and a literal `$`. Returns `None` if nothing matches.
+  pub fn replace(&self, text: &str, replacement: &str) -> Option<String> {
+    let groups: Vec<Option<std::ops::Range<usize>>> = match self {
+      HybridRegex::Optimize(reg) => {
+        let captures = reg.captures(text)?;
+        captures.iter().map(|group| group.map(|m| m.range())).collect()
+      }
+      HybridRegex::Ecma(reg) => reg.find(text)?.groups().collect(),
+    };
+    let whole = groups[0].clone().expect("the whole match should always exist");
+    let group_text = |idx: usize| groups[idx].clone().map_or("", |range| &text[range]);
+
+    let mut output = String::with_capacity(text.len() + replacement.len());
+    output.push_str(&text[..whole.start]);
+    let mut rest = replacement;
+    while let Some(dollar) = rest.find('$') {
+      output.push_str(&rest[..dollar]);
+      let after = &rest[dollar + 1..];
+      let digits = after.bytes().take(2).take_while(u8::is_ascii_digit).count();
+      // Prefer a two-digit group reference if such a group exists, like JavaScript does.
+      let group = (1..=digits).rev().find_map(|len| {
+        after[..len]
+          .parse::<usize>()
+          .ok()
+          .filter(|idx| (1..groups.len()).contains(idx))
+          .map(|idx| (idx, len))
+      });
+      if let Some((idx, len)) = group {
+        output.push_str(group_text(idx));
+        rest = &after[len..];
+      } else if let Some(after) = after.strip_prefix('$') {
+        output.push('$');
+        rest = after;
+      } else if let Some(after) = after.strip_prefix('&') {
+        output.push_str(group_text(0));
+        rest = after;
+      } else {
+        output.push('$');
+        rest = after;
+      }
+    }
+    output.push_str(rest);
+    output.push_str(&text[whole.end..]);
+    Some(output)
+  }
 }
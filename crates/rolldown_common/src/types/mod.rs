@@ -1,6 +1,5 @@
 pub mod asset_source;
 pub mod ast_scopes;
-pub mod bundler_file_system;
 pub mod chunk_idx;
 pub mod chunk_kind;
 pub mod entry_point;
@@ -27,4 +26,5 @@ pub mod resource_id;
 pub mod side_effects;
 pub mod stmt_info;
 pub mod symbol_ref;
+pub mod third_party_license;
 pub mod wrap_kind;
@@ -25,4 +25,7 @@ pub struct OutputChunk {
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
   pub preliminary_filename: String,
+  /// The css files to load along with the chunk, including the ones of the chunks it imports statically, in import
+  /// order.
+  pub css_files: Vec<String>,
 }
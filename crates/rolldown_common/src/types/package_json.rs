@@ -8,11 +8,15 @@ pub struct PackageJson {
   pub path: PathBuf,
   pub r#type: Option<String>,
   pub side_effects: Option<SideEffects>,
+  pub name: Option<String>,
+  pub version: Option<String>,
+  /// The `license` field, or the legacy `licenses` field joined with ` OR `.
+  pub license: Option<String>,
 }
 
 impl PackageJson {
   pub fn new(path: PathBuf) -> Self {
-    Self { path, r#type: None, side_effects: None }
+    Self { path, r#type: None, side_effects: None, name: None, version: None, license: None }
   }
 
   #[must_use]
@@ -28,6 +32,29 @@ impl PackageJson {
     self
   }
 
+  /// Read `name`, `version` and `license` from the raw `package.json`.
+  #[must_use]
+  pub fn with_license_info(mut self, raw_json: &serde_json::Value) -> Self {
+    let get_str = |key: &str| raw_json.get(key).and_then(|v| v.as_str()).map(ToString::to_string);
+    self.name = get_str("name");
+    self.version = get_str("version");
+    // `{ "license": { "type": "MIT" } }` and `{ "licenses": [{ "type": "MIT" }] }` are deprecated but still common.
+    let license_type = |v: &serde_json::Value| {
+      v.as_str().or_else(|| v.get("type").and_then(|v| v.as_str())).map(ToString::to_string)
+    };
+    self.license = raw_json.get("license").and_then(license_type).or_else(|| {
+      let licenses = raw_json.get("licenses")?.as_array()?;
+      let types = licenses.iter().filter_map(license_type).collect::<Vec<_>>();
+      (!types.is_empty()).then(|| types.join(" OR "))
+    });
+    self
+  }
+
+  /// Whether this `package.json` belongs to a package installed in `node_modules`.
+  pub fn is_third_party(&self) -> bool {
+    self.path.components().any(|c| c.as_os_str() == "node_modules")
+  }
+
   pub fn r#type(&self) -> Option<&str> {
     self.r#type.as_deref()
   }
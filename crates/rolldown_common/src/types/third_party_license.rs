@@ -0,0 +1,10 @@
+/// A npm package that has at least one module included in the bundle.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ThirdPartyLicense {
+  pub name: String,
+  pub version: Option<String>,
+  /// The `license` field of the `package.json`, such as `MIT`.
+  pub license: Option<String>,
+  /// Content of the `LICENSE` file of the package.
+  pub license_text: Option<String>,
+}
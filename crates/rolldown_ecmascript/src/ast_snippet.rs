@@ -430,6 +430,37 @@ impl<'ast> AstSnippet<'ast> {
     ))
   }
 
+  /// ```js
+  /// (() => { ... })()
+  /// ```
+  pub fn arrow_iife_expr(
+    &self,
+    span: Span,
+    statements: allocator::Vec<'ast, Statement<'ast>>,
+  ) -> ast::Expression<'ast> {
+    let arrow_expr = self.builder.expression_arrow_function(
+      SPAN,
+      false,
+      false,
+      self.builder.formal_parameters(
+        SPAN,
+        ast::FormalParameterKind::ArrowFormalParameters,
+        self.builder.vec(),
+        None::<Box<'_, BindingRestElement<'_>>>,
+      ),
+      self.builder.function_body(SPAN, self.builder.vec(), statements),
+      None::<Box<'_, TSTypeParameterDeclaration<'_>>>,
+      None::<Box<'_, TSTypeAnnotation<'_>>>,
+    );
+    self.builder.expression_call(
+      span,
+      self.builder.vec(),
+      self.builder.expression_parenthesized(SPAN, arrow_expr),
+      None::<Box<'_, TSTypeParameterInstantiation<'_>>>,
+      false,
+    )
+  }
+
   /// `undefined` is acting like identifier, it might be shadowed by user code.
   pub fn void_zero(&self) -> ast::Expression<'ast> {
     ast::Expression::UnaryExpression(self.builder.alloc_unary_expression(
@@ -591,6 +622,51 @@ impl<'ast> AstSnippet<'ast> {
     ))
   }
 
+  /// ```js
+  /// __loadCss(["./foo.css"], import.meta.url).then(() => import("./foo.js"))
+  /// ```
+  pub fn load_css_then_expr(
+    &self,
+    load_css_fn_name: PassedStr,
+    css_files: Vec<ast::Expression<'ast>>,
+    then_expr: ast::Expression<'ast>,
+  ) -> ast::Expression<'ast> {
+    let files = self.builder.expression_array(
+      SPAN,
+      self.builder.vec_from_iter(css_files.into_iter().map(Into::into)),
+      None,
+    );
+    let import_meta_url = self.builder.expression_member(self.builder.member_expression_static(
+      SPAN,
+      self.builder.expression_meta_property(
+        SPAN,
+        self.id_name("import", SPAN),
+        self.id_name("meta", SPAN),
+      ),
+      self.id_name("url", SPAN),
+      false,
+    ));
+    let load_css_call = self.builder.expression_call(
+      SPAN,
+      self.builder.vec_from_iter([files.into(), import_meta_url.into()]),
+      self.id_ref_expr(load_css_fn_name, SPAN),
+      None::<Box<'_, TSTypeParameterInstantiation<'_>>>,
+      false,
+    );
+    self.builder.expression_call(
+      SPAN,
+      self.builder.vec1(self.only_return_arrow_expr(then_expr).into()),
+      self.builder.expression_member(self.builder.member_expression_static(
+        SPAN,
+        load_css_call,
+        self.id_name("then", SPAN),
+        false,
+      )),
+      None::<Box<'_, TSTypeParameterInstantiation<'_>>>,
+      false,
+    )
+  }
+
   // return xxx
   pub fn return_stmt(&self, argument: ast::Expression<'ast>) -> ast::Statement<'ast> {
     ast::Statement::ReturnStatement(
@@ -1,7 +1,7 @@
 use oxc::{
-  ast::ast::Program,
+  ast::{ast::Program, CommentKind},
   semantic::{ScopeTree, Semantic, SemanticBuilder, SymbolTable},
-  span::SourceType,
+  span::{SourceType, Span},
 };
 
 use crate::EcmaAst;
@@ -11,6 +11,70 @@ impl EcmaAst {
     self.program().is_empty()
   }
 
+  /// Comments starting with `/*!` or `//!`, or containing `@license` or `@preserve`, in source order. The returned span
+  /// and text include the comment delimiters.
+  pub fn legal_comments(&self) -> Vec<(Span, &str)> {
+    let source = self.source();
+    self
+      .trivias
+      .comments()
+      .filter_map(|comment| {
+        let (start, end) = (comment.span.start as usize, comment.span.end as usize);
+        let content = &source[start..end];
+        let is_legal =
+          content.starts_with('!') || content.contains("@license") || content.contains("@preserve");
+        is_legal.then(|| {
+          let span = match comment.kind {
+            CommentKind::SingleLine => Span::new(comment.span.start - 2, comment.span.end),
+            CommentKind::MultiLine => Span::new(comment.span.start - 2, comment.span.end + 2),
+          };
+          (span, &source[span.start as usize..span.end as usize])
+        })
+      })
+      .collect()
+  }
+
+  /// Block comments right before `start`, such as JSDoc comments of a declaration. Annotation comments and legal
+  /// comments are excluded, since they are handled separately. The returned text includes the comment delimiters.
+  pub fn leading_block_comments(&self, start: u32) -> Vec<&str> {
+    let source = self.source();
+    let mut end = start as usize;
+    let mut comments = vec![];
+    for comment in self.trivias.comments_range(0..start).rev() {
+      let comment_start = comment.span.start as usize - 2;
+      let comment_end = match comment.kind {
+        CommentKind::SingleLine => comment.span.end as usize,
+        CommentKind::MultiLine => comment.span.end as usize + 2,
+      };
+      // `export` and `export default` are removed by the finalizer, so comments before them still belong to the
+      // declaration.
+      let between = source[comment_end..end].trim();
+      let between = between.strip_prefix("export").map_or(between, str::trim_start);
+      let between = between.strip_prefix("default").unwrap_or(between);
+      if !between.trim().is_empty() {
+        break;
+      }
+      end = comment_start;
+      let content = &source[comment.span.start as usize..comment.span.end as usize];
+      let is_special = content.starts_with('!')
+        || [
+          "@license",
+          "@preserve",
+          "@__PURE__",
+          "#__PURE__",
+          "@__NO_SIDE_EFFECTS__",
+          "#__NO_SIDE_EFFECTS__",
+        ]
+        .iter()
+        .any(|pattern| content.contains(pattern));
+      if matches!(comment.kind, CommentKind::MultiLine) && !is_special {
+        comments.push(&source[comment_start..comment_end]);
+      }
+    }
+    comments.reverse();
+    comments
+  }
+
   pub fn make_semantic<'ast>(
     source: &'ast str,
     program: &'_ Program<'ast>,
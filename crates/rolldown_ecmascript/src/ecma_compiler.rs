@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use oxc::{
-  codegen::{CodeGenerator, CodegenReturn},
+  allocator::{Allocator, Vec},
+  ast::ast::{Expression, Program, Statement},
+  codegen::{CodeGenerator, CodegenReturn, Context, Gen},
   parser::Parser,
-  span::SourceType,
+  span::{GetSpan, SourceType, Span, SPAN},
 };
 
 use crate::ecma_ast::{
@@ -36,7 +38,19 @@ impl EcmaCompiler {
       contains_use_strict: false,
     })
   }
-  pub fn print(ast: &EcmaAst, source_name: &str, enable_source_map: bool) -> CodegenReturn {
+  /// If `preserve_comments` is `true`, block comments like JSDoc that are attached to top-level statements are printed
+  /// too.
+  ///
+  /// `legal_comments` are printed where they are in the source, which is before the first top-level statement that
+  /// starts after them. Those after the last statement are printed at the end. If no statement comes from the source,
+  /// such as in a wrapped module, they are all printed at the start.
+  pub fn print(
+    ast: &EcmaAst,
+    source_name: &str,
+    enable_source_map: bool,
+    preserve_comments: bool,
+    legal_comments: &[(Span, &str)],
+  ) -> CodegenReturn {
     let mut codegen = CodeGenerator::new().with_capacity(ast.source().len()).enable_comment(
       ast.source(),
       ast.trivias.clone(),
@@ -45,13 +59,165 @@ impl EcmaCompiler {
     if enable_source_map {
       codegen = codegen.enable_source_map(source_name, ast.source());
     }
-    codegen.build(ast.program())
+    if !preserve_comments && legal_comments.is_empty() {
+      return codegen.build(ast.program());
+    }
+
+    // oxc codegen only prints annotation comments, so statements are printed one by one here to put the comments
+    // between them. This mirrors how `Program` is printed by oxc, which the tests below check.
+    let program = ast.program();
+    let ctx = Context::default();
+    if let Some(hashbang) = &program.hashbang {
+      hashbang.gen(&mut codegen, ctx);
+    }
+    if program.directives.is_empty() {
+      // Make sure the first statement isn't printed as a directive.
+      if let Some(Statement::ExpressionStatement(stmt)) = program.body.first() {
+        if matches!(stmt.expression, Expression::StringLiteral(_)) {
+          codegen.print_str(";\n");
+        }
+      }
+    }
+    for directive in &program.directives {
+      directive.gen(&mut codegen, ctx);
+    }
+    let mut legal_comments = legal_comments.iter().peekable();
+    let print_comment = |codegen: &mut CodeGenerator, comment: &str| {
+      codegen.print_str(comment);
+      codegen.print(b'\n');
+    };
+    if program.body.iter().all(|stmt| stmt.span().start == 0) {
+      legal_comments.by_ref().for_each(|(_, comment)| print_comment(&mut codegen, comment));
+    }
+    for stmt in &program.body {
+      let start = stmt.span().start;
+      // Statements created by rolldown have no comments.
+      if start != 0 {
+        while let Some((_, comment)) = legal_comments.next_if(|(span, _)| span.end <= start) {
+          print_comment(&mut codegen, comment);
+        }
+        if preserve_comments {
+          for comment in ast.leading_block_comments(start) {
+            print_comment(&mut codegen, comment);
+          }
+        }
+      }
+      stmt.gen(&mut codegen, ctx);
+    }
+    legal_comments.for_each(|(_, comment)| print_comment(&mut codegen, comment));
+    Self::finish(codegen, program.source_type)
+  }
+
+  /// Returns the code printed so far along with its sourcemap.
+  ///
+  /// oxc codegen 0.20 only hands out the sourcemap from `build`, which prints the given program after what is already
+  /// in the buffer. Printing an empty program adds nothing, so the result is exactly what has been printed. The tests
+  /// below check that this stays identical to `build(program)`, so an oxc upgrade that changes it is caught.
+  fn finish(codegen: CodeGenerator, source_type: SourceType) -> CodegenReturn {
+    let allocator = Allocator::default();
+    let empty_program =
+      Program::new(SPAN, source_type, Vec::new_in(&allocator), None, Vec::new_in(&allocator));
+    codegen.build(&empty_program)
   }
 }
 
 #[test]
 fn basic_test() {
   let ast = EcmaCompiler::parse("const a = 1;".to_string(), SourceType::default()).unwrap();
-  let code = EcmaCompiler::print(&ast, "", false).source_text;
+  let code = EcmaCompiler::print(&ast, "", false, false, &[]).source_text;
   assert_eq!(code, "const a = 1;\n");
 }
+
+#[test]
+fn preserve_comments_test() {
+  let source =
+    "/** a */\nexport const a = 1;\n// b\nconst b = 2;\n/*! legal */\n/** c */ function c() {}\n";
+  let ast =
+    EcmaCompiler::parse(source.to_string(), SourceType::default().with_module(true)).unwrap();
+  let code = EcmaCompiler::print(&ast, "", false, true, &[]).source_text;
+  assert_eq!(code, "/** a */\nexport const a = 1;\nconst b = 2;\n/** c */\nfunction c() {}\n");
+}
+
+#[cfg(test)]
+fn print_with_and_without_comments(source: &str) -> (CodegenReturn, CodegenReturn) {
+  let ast =
+    EcmaCompiler::parse(source.to_string(), SourceType::default().with_module(true)).unwrap();
+  (
+    EcmaCompiler::print(&ast, "main.js", true, false, &[]),
+    EcmaCompiler::print(&ast, "main.js", true, true, &[]),
+  )
+}
+
+#[test]
+fn preserve_comments_matches_plain_output_without_comments() {
+  for source in [
+    "#!/usr/bin/env node\nconst a = 1;\nconsole.log(a);\n",
+    "'use strict';\n'use custom';\nexport const a = 1;\n",
+    "#!/usr/bin/env node\n'use strict';\nfunction f(a) {\n  return a;\n}\nexport default f;\n",
+    "// line comment\nconst a = /* #__PURE__ */ f();\nexport { a };\n",
+  ] {
+    let (plain, with_comments) = print_with_and_without_comments(source);
+    assert_eq!(plain.source_text, with_comments.source_text, "{source}");
+    assert_eq!(
+      plain.source_map.unwrap().to_json_string().unwrap(),
+      with_comments.source_map.unwrap().to_json_string().unwrap(),
+      "{source}"
+    );
+  }
+}
+
+#[test]
+fn preserve_comments_only_shifts_sourcemap_lines() {
+  let source = "'use strict';\n/** a */\nexport const a = 1;\n/**\n * b\n */\nfunction b() {}\n";
+  let (plain, with_comments) = print_with_and_without_comments(source);
+  assert_eq!(
+    with_comments.source_text,
+    "'use strict';\n/** a */\nexport const a = 1;\n/**\n * b\n */\nfunction b() {}\n"
+  );
+  let plain_map = plain.source_map.unwrap();
+  let comments_map = with_comments.source_map.unwrap();
+  let plain_tokens = plain_map.get_tokens().collect::<std::vec::Vec<_>>();
+  let comments_tokens = comments_map.get_tokens().collect::<std::vec::Vec<_>>();
+  assert_eq!(plain_tokens.len(), comments_tokens.len());
+  for (plain, with_comments) in plain_tokens.into_iter().zip(comments_tokens) {
+    let inserted_lines = match plain.get_dst_line() {
+      0 => 0,
+      1 => 1,
+      _ => 4,
+    };
+    assert_eq!(
+      (plain.get_src_line(), plain.get_src_col(), plain.get_dst_col()),
+      (with_comments.get_src_line(), with_comments.get_src_col(), with_comments.get_dst_col())
+    );
+    assert_eq!(plain.get_dst_line() + inserted_lines, with_comments.get_dst_line());
+  }
+}
+
+#[test]
+fn preserve_comments_keeps_leading_string_statement_apart_from_directives() {
+  let mut ast =
+    EcmaCompiler::parse("0;\n'not a directive';\n".to_string(), SourceType::default()).unwrap();
+  ast.program.with_mut(|fields| {
+    fields.program.body.remove(0);
+  });
+  let plain = EcmaCompiler::print(&ast, "main.js", true, false, &[]);
+  let with_comments = EcmaCompiler::print(&ast, "main.js", true, true, &[]);
+  assert_eq!(plain.source_text, ";\n'not a directive';\n");
+  assert_eq!(plain.source_text, with_comments.source_text);
+  assert_eq!(
+    plain.source_map.unwrap().to_json_string().unwrap(),
+    with_comments.source_map.unwrap().to_json_string().unwrap()
+  );
+}
+
+#[test]
+fn legal_comments_are_printed_in_place() {
+  let source = "/*! a */\nconst a = 1;\n/** b */\nfunction b() {\n  //! inner\n}\n// @license c\n";
+  let ast =
+    EcmaCompiler::parse(source.to_string(), SourceType::default().with_module(true)).unwrap();
+  let legal_comments = ast.legal_comments();
+  let code = EcmaCompiler::print(&ast, "", false, false, &legal_comments).source_text;
+  assert_eq!(code, "/*! a */\nconst a = 1;\nfunction b() {}\n//! inner\n// @license c\n");
+  let code = EcmaCompiler::print(&ast, "", false, true, &legal_comments).source_text;
+  assert_eq!(code, "/*! a */\nconst a = 1;\n/** b */\nfunction b() {}\n//! inner\n// @license c\n");
+}
@@ -10,10 +10,12 @@ use super::BuildError;
 
 use crate::events::{
   circular_dependency::CircularDependency, eval::Eval, external_entry::ExternalEntry,
-  forbid_const_assign::ForbidConstAssign, missing_export::MissingExport,
-  sourcemap_error::SourceMapError, unresolved_entry::UnresolvedEntry,
-  unresolved_import::UnresolvedImport,
-  unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal, NapiError,
+  forbid_const_assign::ForbidConstAssign, ignored_module::IgnoredModule,
+  invalid_option::InvalidOption, missing_export::MissingExport,
+  missing_node_builtins::MissingNodeBuiltins, sourcemap_error::SourceMapError,
+  unresolved_entry::UnresolvedEntry, unresolved_import::UnresolvedImport,
+  unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
+  unsupported_decorator::UnsupportedDecorator, NapiError,
 };
 
 impl BuildError {
@@ -72,6 +74,14 @@ impl BuildError {
     })
   }
 
+  pub fn invalid_option(option: impl Into<String>, explanation: impl Into<String>) -> Self {
+    Self::new_inner(InvalidOption { option: option.into(), explanation: explanation.into() })
+  }
+
+  pub fn missing_node_builtins(specifier: impl Into<String>, importer_chain: Vec<PathBuf>) -> Self {
+    Self::new_inner(MissingNodeBuiltins { specifier: specifier.into(), importer_chain })
+  }
+
   // --- Rolldown related
 
   pub fn forbid_const_assign(
@@ -91,4 +101,25 @@ impl BuildError {
   pub fn eval(filename: String, source: Arc<str>, span: Span) -> Self {
     Self::new_inner(Eval { filename, span, source })
   }
+
+  pub fn ignored_module(
+    specifier: impl Into<String>,
+    importer: impl AsRef<Path>,
+    browser_mapping: Option<(PathBuf, String)>,
+  ) -> Self {
+    Self::new_inner(IgnoredModule {
+      specifier: specifier.into(),
+      importer: importer.as_ref().to_path_buf(),
+      browser_mapping,
+    })
+  }
+
+  pub fn unsupported_decorator(
+    filename: String,
+    source: Arc<str>,
+    span: Span,
+    reason: impl Into<String>,
+  ) -> Self {
+    Self::new_inner(UnsupportedDecorator { filename, source, span, reason: reason.into() })
+  }
 }
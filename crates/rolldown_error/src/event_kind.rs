@@ -8,8 +8,12 @@ pub enum EventKind {
   CircularDependency,
   SourcemapError,
   MissingExport,
+  InvalidOption,
+  MissingNodeBuiltins,
   // --- These kinds are rolldown specific
   IllegalReassignment,
+  IgnoredModule,
+  UnsupportedDecorator,
   // !! Only add new kind if it's not covered by the kinds from rollup !!
 
   // TODO remove following kinds
@@ -28,7 +32,11 @@ impl Display for EventKind {
       EventKind::SourcemapError => write!(f, "SOURCEMAP_ERROR"),
       EventKind::CircularDependency => write!(f, "CIRCULAR_DEPENDENCY"),
       EventKind::MissingExport => write!(f, "MISSING_EXPORT"),
+      EventKind::InvalidOption => write!(f, "INVALID_OPTION"),
+      EventKind::MissingNodeBuiltins => write!(f, "MISSING_NODE_BUILTINS"),
       // --- Rolldown specific
+      EventKind::IgnoredModule => write!(f, "IGNORED_MODULE"),
+      EventKind::UnsupportedDecorator => write!(f, "UNSUPPORTED_DECORATOR"),
       EventKind::NapiError => write!(f, "NAPI_ERROR"),
       EventKind::IoError => write!(f, "IO_ERROR"),
     }
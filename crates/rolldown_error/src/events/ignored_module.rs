@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct IgnoredModule {
+  pub(crate) specifier: String,
+  pub(crate) importer: PathBuf,
+  /// The `package.json` and the key of its `browser` field that maps the module to `false`.
+  pub(crate) browser_mapping: Option<(PathBuf, String)>,
+}
+
+impl BuildEvent for IgnoredModule {
+  fn kind(&self) -> EventKind {
+    EventKind::IgnoredModule
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let reason = match &self.browser_mapping {
+      Some((package_json, key)) => format!(
+        r#" by the mapping "{key}": false in the "browser" field of {}"#,
+        opts.stabilize_path(package_json)
+      ),
+      None => String::new(),
+    };
+    format!(
+      "{:?} imported by {:?} is ignored{reason} - an empty module is used instead, so bindings imported from it are `undefined`.",
+      self.specifier,
+      opts.stabilize_path(&self.importer),
+    )
+  }
+}
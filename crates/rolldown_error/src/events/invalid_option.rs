@@ -0,0 +1,19 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct InvalidOption {
+  pub(crate) option: String,
+  pub(crate) explanation: String,
+}
+
+impl BuildEvent for InvalidOption {
+  fn kind(&self) -> EventKind {
+    EventKind::InvalidOption
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Invalid value for option \"{}\" - {}.", self.option, self.explanation)
+  }
+}
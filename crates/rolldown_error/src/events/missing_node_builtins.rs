@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct MissingNodeBuiltins {
+  pub(crate) specifier: String,
+  /// Modules from an entry to the module importing the builtin.
+  pub(crate) importer_chain: Vec<PathBuf>,
+}
+
+impl BuildEvent for MissingNodeBuiltins {
+  fn kind(&self) -> EventKind {
+    EventKind::MissingNodeBuiltins
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let chain = self
+      .importer_chain
+      .iter()
+      .map(|path| opts.stabilize_path(path))
+      .chain(std::iter::once(self.specifier.clone()))
+      .collect::<Vec<_>>()
+      .join(" -> ");
+    format!(
+      "Creating a browser bundle that depends on the Node.js built-in module {:?}, which is treated as an external dependency. It is imported through {chain}. Map it to a polyfill package with the \"nodePolyfills\" option, or use the \"node\" platform.",
+      self.specifier,
+    )
+  }
+}
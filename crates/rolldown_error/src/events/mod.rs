@@ -8,11 +8,15 @@ pub mod circular_dependency;
 pub mod eval;
 pub mod external_entry;
 pub mod forbid_const_assign;
+pub mod ignored_module;
+pub mod invalid_option;
 pub mod missing_export;
+pub mod missing_node_builtins;
 pub mod sourcemap_error;
 pub mod unresolved_entry;
 pub mod unresolved_import;
 pub mod unresolved_import_treated_as_external;
+pub mod unsupported_decorator;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
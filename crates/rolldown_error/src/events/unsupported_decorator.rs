@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedDecorator {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+  pub reason: String,
+}
+
+impl BuildEvent for UnsupportedDecorator {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnsupportedDecorator
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("{} in '{}'.", self.reason, opts.stabilize_path(&self.filename))
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = format!("{}.", self.reason);
+
+    let file_id = diagnostic.add_file(filename, Arc::clone(&self.source));
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Decorator used here.".to_string(),
+    );
+  }
+}
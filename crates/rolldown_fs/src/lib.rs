@@ -5,6 +5,8 @@ mod memory;
 pub use memory::MemoryFileSystem;
 #[cfg(feature = "os")]
 mod os;
+mod shared_file_system;
 pub use crate::file_system::FileSystem;
+pub use crate::shared_file_system::SharedFileSystem;
 #[cfg(feature = "os")]
 pub use os::OsFileSystem;
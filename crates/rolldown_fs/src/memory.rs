@@ -56,10 +56,16 @@ impl FileSystem for MemoryFileSystem {
   }
 
   fn create_dir_all(&self, path: &Path) -> io::Result<()> {
-    self
-      .fs
-      .create_dir(&path.to_string_lossy())
-      .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    for dir in path.ancestors().collect::<Vec<_>>().iter().rev() {
+      let dir = dir.to_string_lossy();
+      if !self.fs.exists(dir.as_ref()).unwrap_or(false) {
+        self
+          .fs
+          .create_dir(dir.as_ref())
+          .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+      }
+    }
+    Ok(())
   }
 
   fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
@@ -73,7 +79,7 @@ impl FileSystem for MemoryFileSystem {
   }
 
   fn exists(&self, path: &Path) -> bool {
-    self.fs.exists(path.to_string_lossy().as_ref()).is_ok()
+    self.fs.exists(path.to_string_lossy().as_ref()).unwrap_or(false)
   }
 
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
@@ -114,8 +120,13 @@ impl OxcResolverFileSystem for MemoryFileSystem {
     })
   }
 
-  fn canonicalize(&self, _path: &Path) -> io::Result<PathBuf> {
-    Err(io::Error::new(io::ErrorKind::NotFound, "not a symlink"))
+  /// There are no symlinks in memory, so an existing path is already canonical.
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    if crate::FileSystem::exists(self, path) {
+      Ok(path.to_path_buf())
+    } else {
+      Err(io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", path.display())))
+    }
   }
 }
 
@@ -146,11 +157,11 @@ mod tests {
       fs.read_to_string(Path::new("/module_1.js")).map_err(|err| err.to_string())?
     );
 
-    let ret = fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.kind());
-    assert_eq!(Err(std::io::ErrorKind::Other), ret);
-
-    fs.create_dir_all(Path::new("/module_2")).map_err(|err| err.to_string())?;
+    assert!(!fs.exists(Path::new("/module_2")));
     fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.to_string())?;
+    assert!(fs.exists(Path::new("/module_2/utils")));
+    // Existing directories are kept.
+    fs.create_dir_all(Path::new("/module_2")).map_err(|err| err.to_string())?;
 
     let utils_content = b"export const name = \"utils\"";
     fs.write(Path::new("/module_2/utils/index.js"), utils_content)
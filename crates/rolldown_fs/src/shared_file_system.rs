@@ -0,0 +1,74 @@
+use std::{
+  fmt::Debug,
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
+
+use crate::file_system::FileSystem;
+
+/// A cheaply cloneable handle to the file system used by the whole bundler, so the resolver, the module loader,
+/// plugins and `write` all see the same files. Defaults to [crate::OsFileSystem].
+#[derive(Clone)]
+pub struct SharedFileSystem(Arc<dyn FileSystem>);
+
+impl SharedFileSystem {
+  pub fn new(fs: impl FileSystem + 'static) -> Self {
+    Self(Arc::new(fs))
+  }
+}
+
+#[cfg(feature = "os")]
+impl Default for SharedFileSystem {
+  fn default() -> Self {
+    Self::new(crate::OsFileSystem)
+  }
+}
+
+impl Debug for SharedFileSystem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "SharedFileSystem(...)")
+  }
+}
+
+impl FileSystem for SharedFileSystem {
+  fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.remove_dir_all(path)
+  }
+
+  fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.create_dir_all(path)
+  }
+
+  fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    self.0.write(path, content)
+  }
+
+  fn exists(&self, path: &Path) -> bool {
+    self.0.exists(path)
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.0.read(path)
+  }
+}
+
+impl OxcResolverFileSystem for SharedFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    self.0.read_to_string(path)
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.metadata(path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.symlink_metadata(path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.canonicalize(path)
+  }
+}
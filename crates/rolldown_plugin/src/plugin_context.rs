@@ -1,6 +1,7 @@
 use std::sync::{Arc, Weak};
 
 use rolldown_common::{ModuleTable, ResolvedRequestInfo, SharedFileEmitter};
+use rolldown_fs::SharedFileSystem;
 use rolldown_resolver::{ResolveError, Resolver};
 
 use crate::{
@@ -41,6 +42,12 @@ impl PluginContext {
     .await
   }
 
+  /// The file system of the bundler. Plugins should read files through it instead of `std::fs`, so builds could run
+  /// in memory.
+  pub fn fs(&self) -> &SharedFileSystem {
+    self.resolver.fs()
+  }
+
   pub fn emit_file(&self, file: rolldown_common::EmittedAsset) -> String {
     self.file_emitter.emit_file(file)
   }
@@ -1,20 +1,15 @@
 use std::path::Path;
 
-use rolldown_common::{ImportKind, ModuleDefFormat, ResolvedPath, ResolvedRequestInfo};
+use rolldown_common::{
+  is_node_builtin, ImportKind, ModuleDefFormat, ResolvedPath, ResolvedRequestInfo,
+};
 use rolldown_resolver::{ResolveError, Resolver};
+use rolldown_utils::url::{is_data_url, is_http_url};
 
 use crate::{
   HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdExtraOptions, PluginDriver,
 };
 
-fn is_http_url(s: &str) -> bool {
-  s.starts_with("http://") || s.starts_with("https://") || s.starts_with("//")
-}
-
-fn is_data_url(s: &str) -> bool {
-  s.trim_start().starts_with("data:")
-}
-
 pub async fn resolve_id_with_plugins(
   resolver: &Resolver,
   plugin_driver: &PluginDriver,
@@ -58,8 +53,19 @@ pub async fn resolve_id_with_plugins(
     }));
   }
 
-  // Auto external http url or data url
-  if is_http_url(request) || is_data_url(request) {
+  // Data urls are virtual modules whose content is decoded from the url
+  if is_data_url(request) {
+    return Ok(Ok(ResolvedRequestInfo {
+      path: request.trim().to_string().into(),
+      module_type: ModuleDefFormat::Unknown,
+      is_external: false,
+      package_json: None,
+      side_effects: None,
+    }));
+  }
+
+  // Auto external http url
+  if is_http_url(request) {
     return Ok(Ok(ResolvedRequestInfo {
       path: request.to_string().into(),
       module_type: ModuleDefFormat::Unknown,
@@ -69,33 +75,37 @@ pub async fn resolve_id_with_plugins(
     }));
   }
 
-  resolve_id(resolver, request, importer, import_kind)
+  resolve_id(resolver, request, importer, import_kind).await
 }
 
-fn resolve_id(
+async fn resolve_id(
   resolver: &Resolver,
   request: &str,
   importer: Option<&str>,
   import_kind: ImportKind,
 ) -> anyhow::Result<Result<ResolvedRequestInfo, ResolveError>> {
-  let resolved = resolver.resolve(importer.map(Path::new), request, import_kind)?;
+  let resolved = resolver.resolve(importer.map(Path::new), request, import_kind).await?;
 
   if let Err(err) = resolved {
     match err {
-      ResolveError::Builtin(specifier) => Ok(Ok(ResolvedRequestInfo {
-        path: ResolvedPath { path: specifier.into(), ignored: false },
-        is_external: true,
-        module_type: ModuleDefFormat::Unknown,
-        package_json: None,
-        side_effects: None,
-      })),
+      ResolveError::Builtin(specifier) if is_node_builtin(&specifier) => {
+        Ok(Ok(ResolvedRequestInfo {
+          path: ResolvedPath { path: specifier.into(), ignored: false },
+          is_external: true,
+          module_type: ModuleDefFormat::Unknown,
+          package_json: None,
+          side_effects: None,
+        }))
+      }
+      // `oxc_resolver` considers every `node:` specifier a builtin, even if there's no such module
+      ResolveError::Builtin(specifier) => Ok(Err(ResolveError::NotFound(specifier))),
       _ => Ok(Err(err)),
     }
   } else {
     Ok(resolved.map(|resolved| ResolvedRequestInfo {
       path: resolved.path,
       module_type: resolved.module_type,
-      is_external: false,
+      is_external: resolved.is_external,
       package_json: resolved.package_json,
       side_effects: None,
     }))
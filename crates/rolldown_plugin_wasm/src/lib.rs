@@ -1,6 +1,7 @@
-use std::{borrow::Cow, fs, path::Path};
+use std::{borrow::Cow, path::Path};
 
 use rolldown_common::{AssetSource, EmittedAsset};
+use rolldown_fs::FileSystem;
 use rolldown_plugin::{
   HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
   HookResolveIdReturn, Plugin, SharedPluginContext,
@@ -42,7 +43,7 @@ impl Plugin for WasmPlugin {
       let file_path = Path::new(&id);
       let reference_id = ctx.emit_file(EmittedAsset {
         name: file_path.file_name().map(|x| x.to_string_lossy().to_string()),
-        source: AssetSource::Buffer(fs::read(file_path)?),
+        source: AssetSource::Buffer(ctx.fs().read(file_path)?),
         file_name: None,
       });
       let url = ctx.get_file_name(&reference_id);
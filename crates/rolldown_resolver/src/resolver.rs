@@ -1,9 +1,9 @@
 use dashmap::DashMap;
 use itertools::Itertools;
 use rolldown_common::{
-  ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolvedPath,
+  AliasRule, ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolvedPath,
 };
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use std::{
   path::{Path, PathBuf},
   sync::Arc,
@@ -12,21 +12,40 @@ use sugar_path::SugarPath;
 
 use oxc_resolver::{
   EnforceExtension, PackageJson as OxcPackageJson, Resolution, ResolveError,
-  ResolveOptions as OxcResolverOptions, ResolverGeneric, TsconfigOptions,
+  ResolveOptions as OxcResolverOptions, ResolverGeneric, Restriction, TsconfigOptions,
+  TsconfigReferences,
 };
 
+/// The import and require resolvers sharing one `tsconfig.json`.
+type TsconfigResolvers<T> = Arc<(ResolverGeneric<T>, ResolverGeneric<T>)>;
+
 #[derive(Debug)]
 #[allow(dead_code)]
-pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
+pub struct Resolver<T: FileSystem + Default = SharedFileSystem> {
   cwd: PathBuf,
   default_resolver: ResolverGeneric<T>,
   import_resolver: ResolverGeneric<T>,
   require_resolver: ResolverGeneric<T>,
   package_json_cache: DashMap<PathBuf, Arc<PackageJson>>,
+  invalid_options: Vec<(&'static str, String)>,
+  alias_rules: Vec<AliasRule>,
+  fs: T,
+  tsconfig_discovery: bool,
+  /// The nearest `tsconfig.json` of each importer directory.
+  nearest_tsconfig_cache: DashMap<PathBuf, Option<PathBuf>>,
+  /// The import and require resolvers using a discovered `tsconfig.json`, keyed by its path. They share the cache of
+  /// `default_resolver`, so each `tsconfig.json` is only parsed once.
+  tsconfig_resolvers: DashMap<PathBuf, TsconfigResolvers<T>>,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
-  pub fn new(raw_resolve: ResolveOptions, platform: Platform, cwd: PathBuf, fs: F) -> Self {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
+  #[allow(clippy::too_many_lines)]
+  pub fn new(mut raw_resolve: ResolveOptions, platform: Platform, cwd: PathBuf, fs: F) -> Self {
+    let invalid_options = raw_resolve.validate();
+    let alias_rules = raw_resolve.alias_rules.take().unwrap_or_default();
+    let tsconfig_discovery =
+      raw_resolve.tsconfig_discovery.unwrap_or(raw_resolve.tsconfig_filename.is_none());
+
     let mut default_conditions = vec!["default".to_string()];
     let mut import_conditions = vec!["import".to_string()];
     let mut require_conditions = vec!["require".to_string()];
@@ -70,20 +89,10 @@ impl<F: FileSystem + Default> Resolver<F> {
         let path = PathBuf::from(&p);
         TsconfigOptions {
           config_file: if path.is_relative() { cwd.join(path) } else { path },
-          references: oxc_resolver::TsconfigReferences::Disabled,
+          references: TsconfigReferences::Auto,
         }
       }),
-      alias: raw_resolve
-        .alias
-        .map(|alias| {
-          alias
-            .into_iter()
-            .map(|(key, value)| {
-              (key, value.into_iter().map(oxc_resolver::AliasValue::Path).collect::<Vec<_>>())
-            })
-            .collect::<Vec<_>>()
-        })
-        .unwrap_or_default(),
+      alias: raw_resolve.alias.map(|alias| to_oxc_alias(alias.into_iter())).unwrap_or_default(),
       imports_fields: vec![vec!["imports".to_string()]],
       alias_fields,
       condition_names: default_conditions,
@@ -92,20 +101,34 @@ impl<F: FileSystem + Default> Resolver<F> {
       exports_fields: raw_resolve
         .exports_fields
         .unwrap_or_else(|| vec![vec!["exports".to_string()]]),
-      extension_alias: vec![],
-      extensions: raw_resolve.extensions.unwrap_or_else(|| {
-        [".jsx", ".js", ".ts", ".tsx"].into_iter().map(str::to_string).collect()
-      }),
-      fallback: vec![],
-      fully_specified: false,
+      // Invalid entries are reported by `ResolveOptions::validate`, they are dropped here since oxc_resolver can't handle them.
+      extension_alias: raw_resolve.extension_alias.map(valid_extension_alias).unwrap_or_default(),
+      extensions: raw_resolve.extensions.map_or_else(
+        || [".jsx", ".js", ".ts", ".tsx"].into_iter().map(str::to_string).collect(),
+        |extensions| {
+          extensions.into_iter().filter(|ext| ext.is_empty() || ext.starts_with('.')).collect()
+        },
+      ),
+      fallback: raw_resolve
+        .fallback
+        .map(|fallback| {
+          to_oxc_alias(fallback.into_iter().filter(|(name, to)| !name.is_empty() && !to.is_empty()))
+        })
+        .unwrap_or_default(),
+      fully_specified: raw_resolve.fully_specified.unwrap_or(false),
       main_fields,
       main_files: raw_resolve.main_files.unwrap_or_else(|| vec!["index".to_string()]),
       modules: raw_resolve.modules.unwrap_or_else(|| vec!["node_modules".to_string()]),
       resolve_to_context: false,
-      prefer_relative: false,
-      prefer_absolute: false,
-      restrictions: vec![],
-      roots: vec![],
+      prefer_relative: raw_resolve.prefer_relative.unwrap_or(false),
+      prefer_absolute: raw_resolve.prefer_absolute.unwrap_or(false),
+      restrictions: raw_resolve
+        .restrictions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| Restriction::Path(cwd.join(p)))
+        .collect(),
+      roots: raw_resolve.roots.unwrap_or_default().into_iter().map(|p| cwd.join(p)).collect(),
       symlinks: raw_resolve.symlinks.unwrap_or(true),
       builtin_modules,
     };
@@ -118,7 +141,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       ..resolve_options_with_default_conditions.clone()
     };
     let default_resolver =
-      ResolverGeneric::new_with_file_system(fs, resolve_options_with_default_conditions);
+      ResolverGeneric::new_with_file_system(fs.clone(), resolve_options_with_default_conditions);
     let import_resolver =
       default_resolver.clone_with_options(resolve_options_with_import_conditions);
     let require_resolver =
@@ -130,12 +153,28 @@ impl<F: FileSystem + Default> Resolver<F> {
       import_resolver,
       require_resolver,
       package_json_cache: DashMap::default(),
+      invalid_options,
+      alias_rules,
+      fs,
+      tsconfig_discovery,
+      nearest_tsconfig_cache: DashMap::default(),
+      tsconfig_resolvers: DashMap::default(),
     }
   }
 
   pub fn cwd(&self) -> &PathBuf {
     &self.cwd
   }
+
+  /// The file system that modules are resolved in.
+  pub fn fs(&self) -> &F {
+    &self.fs
+  }
+
+  /// The name and the reason of each invalid resolve option.
+  pub fn invalid_options(&self) -> &[(&'static str, String)] {
+    &self.invalid_options
+  }
 }
 
 #[derive(Debug)]
@@ -143,18 +182,49 @@ pub struct ResolveReturn {
   pub path: ResolvedPath,
   pub module_type: ModuleDefFormat,
   pub package_json: Option<Arc<PackageJson>>,
+  /// Set if an external alias rule matched the specifier.
+  pub is_external: bool,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
-  pub fn resolve(
+impl<F: FileSystem + Default + Clone> Resolver<F> {
+  pub async fn resolve(
     &self,
     importer: Option<&Path>,
     specifier: &str,
     import_kind: ImportKind,
   ) -> anyhow::Result<Result<ResolveReturn, ResolveError>> {
+    let aliased_specifier;
+    let specifier = match self.apply_alias_rules(specifier, importer).await? {
+      Some((aliased, true)) => {
+        return Ok(Ok(ResolveReturn {
+          path: aliased.into(),
+          module_type: ModuleDefFormat::Unknown,
+          package_json: None,
+          is_external: true,
+        }));
+      }
+      Some((aliased, false)) => {
+        aliased_specifier = aliased;
+        aliased_specifier.as_str()
+      }
+      None => specifier,
+    };
+
+    let discovered = if self.tsconfig_discovery {
+      self.discovered_tsconfig_resolvers(importer.and_then(Path::parent).unwrap_or(&self.cwd))
+    } else {
+      None
+    };
+    let (import_resolver, require_resolver) = discovered
+      .as_deref()
+      .map_or((&self.import_resolver, &self.require_resolver), |(import, require)| {
+        (import, require)
+      });
     let selected_resolver = match import_kind {
-      ImportKind::Import | ImportKind::DynamicImport => &self.import_resolver,
-      ImportKind::Require => &self.require_resolver,
+      ImportKind::Import | ImportKind::DynamicImport | ImportKind::NewUrl | ImportKind::Worker => {
+        import_resolver
+      }
+      ImportKind::Require => require_resolver,
     };
     let resolution = if let Some(importer) = importer {
       let context = importer.parent().expect("Should have a parent dir");
@@ -203,6 +273,60 @@ impl<F: FileSystem + Default> Resolver<F> {
     }
   }
 
+  /// Resolvers using the nearest `tsconfig.json` of `dir` that's not above `cwd`. Returns `None` to fall back to
+  /// `tsconfig_filename` if there is no such file or `dir` is inside `node_modules`.
+  fn discovered_tsconfig_resolvers(&self, dir: &Path) -> Option<TsconfigResolvers<F>> {
+    let tsconfig = self
+      .nearest_tsconfig_cache
+      .entry(dir.to_path_buf())
+      .or_insert_with(|| {
+        if dir.components().any(|c| c.as_os_str() == "node_modules") {
+          return None;
+        }
+        dir
+          .ancestors()
+          .take_while(|dir| dir.starts_with(&self.cwd))
+          .map(|dir| dir.join("tsconfig.json"))
+          .find(|path| self.fs.exists(path))
+      })
+      .clone()?;
+
+    let resolvers = self.tsconfig_resolvers.entry(tsconfig.clone()).or_insert_with(|| {
+      let with_tsconfig = |resolver: &ResolverGeneric<F>| {
+        resolver.clone_with_options(OxcResolverOptions {
+          tsconfig: Some(TsconfigOptions {
+            config_file: tsconfig.clone(),
+            references: TsconfigReferences::Auto,
+          }),
+          ..resolver.options().clone()
+        })
+      };
+      Arc::new((with_tsconfig(&self.import_resolver), with_tsconfig(&self.require_resolver)))
+    });
+    Some(Arc::clone(resolvers.value()))
+  }
+
+  /// Returns the specifier rewritten by the first matching alias rule and whether it's external.
+  async fn apply_alias_rules(
+    &self,
+    specifier: &str,
+    importer: Option<&Path>,
+  ) -> anyhow::Result<Option<(String, bool)>> {
+    let importer = importer.and_then(Path::to_str);
+    for rule in &self.alias_rules {
+      let Some(aliased) = rule.apply(specifier, importer).await? else {
+        continue;
+      };
+      let aliased = if aliased.starts_with("./") || aliased.starts_with("../") {
+        self.cwd.join(&aliased).normalize().to_str().expect("Should be valid utf8").to_string()
+      } else {
+        aliased
+      };
+      return Ok(Some((aliased, rule.external)));
+    }
+    Ok(None)
+  }
+
   fn cached_package_json(&self, oxc_pkg_json: &OxcPackageJson) -> Arc<PackageJson> {
     if let Some(v) = self.package_json_cache.get(&oxc_pkg_json.realpath) {
       Arc::clone(v.value())
@@ -210,7 +334,8 @@ impl<F: FileSystem + Default> Resolver<F> {
       let pkg_json = Arc::new(
         PackageJson::new(oxc_pkg_json.path.clone())
           .with_type(oxc_pkg_json.r#type.as_ref())
-          .with_side_effects(oxc_pkg_json.side_effects.as_ref()),
+          .with_side_effects(oxc_pkg_json.side_effects.as_ref())
+          .with_license_info(oxc_pkg_json.raw_json()),
       );
       self.package_json_cache.insert(oxc_pkg_json.realpath.clone(), Arc::clone(&pkg_json));
       pkg_json
@@ -243,5 +368,29 @@ fn build_resolve_ret(
   module_type: ModuleDefFormat,
   package_json: Option<Arc<PackageJson>>,
 ) -> ResolveReturn {
-  ResolveReturn { path: ResolvedPath { path: path.into(), ignored }, module_type, package_json }
+  ResolveReturn {
+    path: ResolvedPath { path: path.into(), ignored },
+    module_type,
+    package_json,
+    is_external: false,
+  }
+}
+
+fn to_oxc_alias(alias: impl Iterator<Item = (String, Vec<String>)>) -> oxc_resolver::Alias {
+  alias
+    .map(|(key, value)| {
+      (key, value.into_iter().map(oxc_resolver::AliasValue::Path).collect::<Vec<_>>())
+    })
+    .collect::<Vec<_>>()
+}
+
+fn valid_extension_alias(
+  extension_alias: Vec<(String, Vec<String>)>,
+) -> Vec<(String, Vec<String>)> {
+  extension_alias
+    .into_iter()
+    .filter(|(ext, aliases)| {
+      !aliases.is_empty() && std::iter::once(ext).chain(aliases).all(|ext| ext.starts_with('.'))
+    })
+    .collect()
 }
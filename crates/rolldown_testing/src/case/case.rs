@@ -6,7 +6,7 @@ use regex::Regex;
 use rolldown::BundleOutput;
 use rolldown_common::Output;
 use rolldown_error::{BuildError, DiagnosticOptions};
-use rolldown_sourcemap::SourcemapVisualizer;
+use rolldown_sourcemap::{SourceMap, SourcemapVisualizer};
 
 pub struct Case {
   fixture: Fixture,
@@ -79,16 +79,31 @@ impl Case {
     assets.sort_by_key(|c| c.filename().to_string());
     let artifacts = assets
       .iter()
-      .filter(|asset| !asset.filename().contains("$runtime$") && matches!(asset, Output::Chunk(_)))
+      .filter(|asset| {
+        !asset.filename().contains("$runtime$")
+          && match asset {
+            Output::Chunk(_) => true,
+            // Sourcemaps are rendered by the sourcemap visualizer.
+            Output::Asset(asset) => !has_extension(&asset.filename, "map"),
+          }
+      })
       .flat_map(|asset| {
-        let content = std::str::from_utf8(asset.content_as_bytes()).unwrap();
-        let content = if self.fixture.test_config().hidden_runtime_module {
-          RUNTIME_MODULE_OUTPUT_RE.replace_all(content, "")
-        } else {
-          Cow::Borrowed(content)
+        let content = String::from_utf8_lossy(asset.content_as_bytes());
+        let (content, lang) = match asset {
+          Output::Chunk(_) if self.fixture.test_config().hidden_runtime_module => {
+            (Cow::Owned(RUNTIME_MODULE_OUTPUT_RE.replace_all(&content, "").into_owned()), "js")
+          }
+          Output::Chunk(_) => (content, "js"),
+          Output::Asset(asset) if has_extension(&asset.filename, "css") => (content, "css"),
+          Output::Asset(_) => (content, "text"),
         };
 
-        [Cow::Owned(format!("## {}\n", asset.filename())), "```js".into(), content, "```".into()]
+        [
+          Cow::Owned(format!("## {}\n", asset.filename())),
+          Cow::Owned(format!("```{lang}")),
+          content,
+          "```".into(),
+        ]
       })
       .collect::<Vec<_>>()
       .join("\n");
@@ -157,6 +172,17 @@ impl Case {
           .map
           .as_ref()
           .map(|sourcemap| SourcemapVisualizer::new(&chunk.code, sourcemap).into_visualizer_text()),
+        // Sourcemaps of css assets are emitted as separate assets.
+        Output::Asset(asset) if has_extension(&asset.filename, "css") => {
+          let map_filename = format!("{}.map", asset.filename);
+          assets.iter().find(|map| map.filename() == map_filename).map(|map| {
+            let code = String::from_utf8_lossy(asset.source.as_bytes());
+            let map = String::from_utf8_lossy(map.content_as_bytes());
+            let sourcemap =
+              SourceMap::from_json_string(&map).expect("css sourcemap should be valid");
+            SourcemapVisualizer::new(&code, &sourcemap).into_visualizer_text()
+          })
+        }
         Output::Asset(_) => None,
       })
       .collect::<Vec<_>>()
@@ -178,6 +204,10 @@ impl Case {
   }
 }
 
+fn has_extension(filename: &str, extension: &str) -> bool {
+  Path::new(filename).extension().is_some_and(|ext| ext == extension)
+}
+
 static RUNTIME_MODULE_OUTPUT_RE: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"(//#region rolldown:runtime[\s\S]*?//#endregion)")
     .expect("invalid runtime module output regex")
@@ -7,3 +7,13 @@ pub fn to_url_safe_base64(input: impl AsRef<[u8]>) -> String {
 pub fn to_standard_base64(input: impl AsRef<[u8]>) -> String {
   general_purpose::STANDARD.encode(input)
 }
+
+/// Decodes both the standard and the url-safe alphabets, with or without padding.
+pub fn from_base64(input: &str) -> Result<Vec<u8>, base64::DecodeError> {
+  let input = input.trim_end_matches('=');
+  if input.contains(['-', '_']) {
+    general_purpose::URL_SAFE_NO_PAD.decode(input)
+  } else {
+    general_purpose::STANDARD_NO_PAD.decode(input)
+  }
+}
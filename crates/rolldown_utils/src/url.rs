@@ -0,0 +1,44 @@
+use crate::base64::from_base64;
+
+pub fn is_http_url(s: &str) -> bool {
+  s.starts_with("http://") || s.starts_with("https://") || s.starts_with("//")
+}
+
+pub fn is_data_url(s: &str) -> bool {
+  s.trim_start().starts_with("data:")
+}
+
+/// The content of a `data:[<mediatype>][;base64],<data>` url.
+#[derive(Debug)]
+pub struct DataUrl {
+  /// The media type without parameters like `charset`. Defaults to `text/plain`.
+  pub mime: String,
+  pub data: Vec<u8>,
+}
+
+/// Returns `None` if `url` isn't a well-formed data url.
+pub fn parse_data_url(url: &str) -> Option<DataUrl> {
+  let (header, data) = url.trim().strip_prefix("data:")?.split_once(',')?;
+  let mut params = header.split(';');
+  let mime = params.next().map(str::trim).filter(|mime| !mime.is_empty()).unwrap_or("text/plain");
+  let data = if params.any(|param| param.trim() == "base64") {
+    from_base64(data.trim()).ok()?
+  } else {
+    urlencoding::decode_binary(data.as_bytes()).into_owned()
+  };
+  Some(DataUrl { mime: mime.to_ascii_lowercase(), data })
+}
+
+#[test]
+fn test_parse_data_url() {
+  let url = parse_data_url("data:text/javascript;charset=utf-8,export%20default%201").unwrap();
+  assert_eq!(url.mime, "text/javascript");
+  assert_eq!(url.data, b"export default 1");
+
+  let url = parse_data_url("data:application/json;base64,eyJhIjoxfQ==").unwrap();
+  assert_eq!(url.mime, "application/json");
+  assert_eq!(url.data, br#"{"a":1}"#);
+
+  assert_eq!(parse_data_url("data:,hello").unwrap().mime, "text/plain");
+  assert!(parse_data_url("data:text/plain").is_none());
+}
@@ -40,6 +40,7 @@ export declare class BindingOutputChunk {
   get sourcemapFileName(): string | null
   get preliminaryFileName(): string
   get name(): string
+  get cssFiles(): Array<string>
 }
 
 /** The `BindingOutputs` owner `Vec<Output>` the mutable reference, it avoid `Clone` at call `writeBundle/generateBundle` hook, and make it mutable. */
@@ -88,6 +89,17 @@ export interface AliasItem {
   replacements: Array<string>
 }
 
+export interface AliasRuleItem {
+  /** The exact specifier to replace, or the source of a regex if `flags` is set. */
+  find?: string
+  /** The flags of the regex, like `i` in `/react/i`. */
+  flags?: string
+  replacement?: string
+  /** Returns the new specifier, or nothing if the rule doesn't apply. Used instead of `find` and `replacement`. */
+  findFn?: (specifier: string, importer: Nullable<string>) => MaybePromise<VoidNullable<string>>
+  external?: boolean
+}
+
 export interface ArrowFunctionsBindingOptions {
   spec?: boolean
 }
@@ -106,6 +118,17 @@ export declare enum BindingBuiltinPluginName {
   GlobImportPlugin = 1
 }
 
+export interface BindingCssModulesOptions {
+  pattern?: string
+  dashedIdents?: boolean
+}
+
+export interface BindingDropOptions {
+  console?: boolean
+  debugger?: boolean
+  labels?: Array<string>
+}
+
 export interface BindingEmittedAsset {
   name?: string
   fileName?: string
@@ -125,7 +148,7 @@ export interface BindingHookRenderChunkOutput {
 
 export interface BindingHookResolveIdExtraOptions {
   isEntry: boolean
-  kind: 'import' | 'dynamic-import' | 'require-call'
+  kind: 'import' | 'dynamic-import' | 'require-call' | 'new-url' | 'worker'
 }
 
 export interface BindingHookResolveIdOutput {
@@ -154,15 +177,23 @@ export interface BindingInputItem {
 export interface BindingInputOptions {
   external?: undefined | ((source: string, importer: string | undefined, isResolved: boolean) => boolean)
   input: Array<BindingInputItem>
+  makeAbsoluteExternalsRelative?: boolean
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
   resolve?: BindingResolveOptions
   shimMissingExports?: boolean
   platform?: 'node' | 'browser' | 'neutral'
+  nodePolyfills?: Record<string, string>
+  httpImports?: string
+  virtualModules?: Record<string, BindingVirtualModule>
   logLevel?: BindingLogLevel
   onLog: (logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void
   cwd: string
   treeshake?: BindingTreeshake
   moduleTypes?: Record<string, string>
+  cssModules?: BindingCssModulesOptions
+  assetInlineLimit?: number
+  decorators?: 'legacy' | 'standard' | '2023-11'
+  drop?: BindingDropOptions
 }
 
 export interface BindingJsonSourcemap {
@@ -174,6 +205,11 @@ export interface BindingJsonSourcemap {
   names?: Array<string>
 }
 
+export interface BindingLicenseReportOptions {
+  fileName?: string
+  format?: 'text' | 'json'
+}
+
 export declare enum BindingLogLevel {
   Silent = 0,
   Warn = 1,
@@ -186,14 +222,21 @@ export interface BindingOutputOptions {
   chunkFileNames?: string
   assetFileNames?: string
   banner?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
+  comments?: boolean
   dir?: string
   exports?: 'default' | 'named' | 'none' | 'auto'
   footer?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   format?: 'es' | 'cjs' | 'iife'
+  paths?: Record<string, string>
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
   sourcemap?: 'file' | 'inline' | 'hidden'
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external'
+  licenseReport?: BindingLicenseReportOptions
+  cssMinify?: boolean
+  cssTargets?: Array<string>
+  publicPath?: BindingPublicPathOptions
 }
 
 export interface BindingPluginContextResolvedId {
@@ -202,7 +245,7 @@ export interface BindingPluginContextResolvedId {
 }
 
 export interface BindingPluginContextResolveOptions {
-  importKind?: 'import' | 'dynamic-import' | 'require-call'
+  importKind?: 'import' | 'dynamic-import' | 'require-call' | 'new-url' | 'worker'
 }
 
 export interface BindingPluginOptions {
@@ -227,12 +270,19 @@ export interface BindingPluginWithIndex {
   plugin: BindingPluginOptions
 }
 
+export interface BindingPublicPathOptions {
+  base?: string
+  runtime?: string
+  dynamicImports?: boolean
+}
+
 export interface BindingRenderedModule {
   code?: string
 }
 
 export interface BindingResolveOptions {
   alias?: Array<AliasItem>
+  aliasRules?: Array<AliasRuleItem>
   aliasFields?: Array<Array<string>>
   conditionNames?: Array<string>
   exportsFields?: Array<Array<string>>
@@ -242,6 +292,14 @@ export interface BindingResolveOptions {
   modules?: Array<string>
   symlinks?: boolean
   tsconfigFilename?: string
+  tsconfigDiscovery?: boolean
+  extensionAlias?: Array<ExtensionAliasItem>
+  fallback?: Array<AliasItem>
+  roots?: Array<string>
+  restrictions?: Array<string>
+  fullySpecified?: boolean
+  preferRelative?: boolean
+  preferAbsolute?: boolean
 }
 
 export interface BindingSourcemap {
@@ -252,10 +310,20 @@ export interface BindingTreeshake {
   moduleSideEffects: string
 }
 
+export interface BindingVirtualModule {
+  source: string
+  moduleType?: string
+}
+
 export interface Es2015BindingOptions {
   arrowFunction?: ArrowFunctionsBindingOptions
 }
 
+export interface ExtensionAliasItem {
+  target: string
+  replacements: Array<string>
+}
+
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
 export declare function isolatedDeclaration(filename: string, sourceText: string): IsolatedDeclarationsResult
 
@@ -46,9 +46,11 @@ export function bindingifyInputOptions(
           }
         })()
       : undefined,
+    makeAbsoluteExternalsRelative: options.makeAbsoluteExternalsRelative,
     resolve: options.resolve
       ? (function bindingifyResolve() {
-          const { alias, ...rest } = options.resolve
+          const { alias, aliasRules, extensionAlias, fallback, ...rest } =
+            options.resolve
 
           return {
             alias: alias
@@ -57,11 +59,37 @@ export function bindingifyInputOptions(
                   replacements: [replacement],
                 }))
               : undefined,
+            aliasRules: aliasRules?.map((rule) => {
+              if (!('replacement' in rule)) {
+                return { findFn: rule.find, external: rule.external }
+              }
+              const { find, replacement, external } = rule
+              return find instanceof RegExp
+                ? { find: find.source, flags: find.flags, replacement, external }
+                : { find, replacement, external }
+            }),
+            extensionAlias: extensionAlias
+              ? Object.entries(extensionAlias).map(([target, replacements]) => ({
+                  target,
+                  replacements,
+                }))
+              : undefined,
+            fallback: fallback
+              ? Object.entries(fallback).map(([name, replacement]) => ({
+                  find: name,
+                  replacements: Array.isArray(replacement)
+                    ? replacement
+                    : [replacement],
+                }))
+              : undefined,
             ...rest,
           }
         })()
       : undefined,
     platform: options.platform,
+    nodePolyfills: options.nodePolyfills,
+    httpImports: options.httpImports,
+    virtualModules: options.virtualModules,
     shimMissingExports: options.shimMissingExports,
     // @ts-ignore TODO The typing should import from binding
     logLevel: bindingifyLogLevel(options.logLevel),
@@ -70,6 +98,10 @@ export function bindingifyInputOptions(
     },
     treeshake: options.treeshake,
     moduleTypes: options.moduleTypes,
+    cssModules: options.cssModules,
+    assetInlineLimit: options.assetInlineLimit,
+    decorators: options.decorators,
+    drop: options.drop,
   }
 }
 
@@ -16,6 +16,13 @@ export function bindingifyOutputOptions(
     assetFileNames,
     banner,
     footer,
+    comments,
+    paths,
+    legalComments,
+    licenseReport,
+    cssMinify,
+    cssTargets,
+    publicPath,
   } = outputOptions
   return {
     dir,
@@ -38,6 +45,13 @@ export function bindingifyOutputOptions(
     entryFileNames,
     chunkFileNames,
     assetFileNames,
+    comments,
+    paths,
+    legalComments,
+    licenseReport,
+    cssMinify,
+    cssTargets,
+    publicPath,
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
   }
@@ -25,13 +25,51 @@ const externalSchema = zodExt
       .returns(zodExt.voidNullableWith(z.boolean())),
   )
 
+const moduleTypeSchema = z
+  .literal('js')
+  .or(z.literal('jsx'))
+  .or(z.literal('ts'))
+  .or(z.literal('tsx'))
+  .or(z.literal('json'))
+  .or(z.literal('text'))
+  .or(z.literal('base64'))
+  .or(z.literal('dataurl'))
+  .or(z.literal('binary'))
+  .or(z.literal('empty'))
+  .or(z.literal('css'))
+  .or(z.literal('asset'))
+
 const inputOptionsSchema = z.strictObject({
   input: inputOptionSchema.optional(),
   plugins: zodExt.phantom<RolldownPlugin>().array().optional(),
   external: externalSchema.optional(),
+  makeAbsoluteExternalsRelative: z.boolean().optional(),
   resolve: z
     .strictObject({
       alias: z.record(z.string()).optional(),
+      aliasRules: z
+        .array(
+          z
+            .strictObject({
+              find: z.instanceof(RegExp).or(z.string()),
+              replacement: z.string(),
+              external: z.boolean().optional(),
+            })
+            .or(
+              z.strictObject({
+                find: z
+                  .function()
+                  .args(z.string(), z.string().optional())
+                  .returns(
+                    zodExt
+                      .voidNullableWith(z.string())
+                      .or(z.promise(zodExt.voidNullableWith(z.string()))),
+                  ),
+                external: z.boolean().optional(),
+              }),
+            ),
+        )
+        .optional(),
       aliasFields: z.array(z.array(z.string())).optional(),
       conditionNames: zodExt.optionalStringArray(),
       exportsFields: z.array(z.array(z.string())).optional(),
@@ -41,6 +79,14 @@ const inputOptionsSchema = z.strictObject({
       modules: zodExt.optionalStringArray(),
       symlinks: z.boolean().optional(),
       tsconfigFilename: z.string().optional(),
+      tsconfigDiscovery: z.boolean().optional(),
+      extensionAlias: z.record(z.array(z.string())).optional(),
+      fallback: z.record(z.string().or(z.array(z.string()))).optional(),
+      roots: zodExt.optionalStringArray(),
+      restrictions: zodExt.optionalStringArray(),
+      fullySpecified: z.boolean().optional(),
+      preferRelative: z.boolean().optional(),
+      preferAbsolute: z.boolean().optional(),
     })
     .optional(),
   cwd: z.string().optional(),
@@ -49,6 +95,8 @@ const inputOptionsSchema = z.strictObject({
     .or(z.literal('browser'))
     .or(z.literal('neutral'))
     .optional(),
+  nodePolyfills: z.record(z.string()).optional(),
+  httpImports: z.string().optional(),
   shimMissingExports: z.boolean().optional(),
   treeshake: z.boolean().or(TreeshakingOptionsSchema).optional(),
   logLevel: LogLevelOptionSchema.optional(),
@@ -73,21 +121,34 @@ const inputOptionsSchema = z.strictObject({
         ),
     )
     .optional(),
-  moduleTypes: z
+  moduleTypes: z.record(moduleTypeSchema).optional(),
+  virtualModules: z
     .record(
-      z
-        .literal('js')
-        .or(z.literal('jsx'))
-        .or(z.literal('ts'))
-        .or(z.literal('tsx'))
-        .or(z.literal('json'))
-        .or(z.literal('text'))
-        .or(z.literal('base64'))
-        .or(z.literal('dataurl'))
-        .or(z.literal('binary'))
-        .or(z.literal('empty')),
+      z.strictObject({
+        source: z.string(),
+        moduleType: moduleTypeSchema.optional(),
+      }),
     )
     .optional(),
+  cssModules: z
+    .strictObject({
+      pattern: z.string().optional(),
+      dashedIdents: z.boolean().optional(),
+    })
+    .optional(),
+  assetInlineLimit: z.number().optional(),
+  decorators: z
+    .literal('legacy')
+    .or(z.literal('standard'))
+    .or(z.literal('2023-11'))
+    .optional(),
+  drop: z
+    .strictObject({
+      console: z.boolean().optional(),
+      debugger: z.boolean().optional(),
+      labels: z.array(z.string()).optional(),
+    })
+    .optional(),
 })
 
 export type InputOption = z.infer<typeof inputOptionSchema>
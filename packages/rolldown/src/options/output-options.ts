@@ -37,6 +37,30 @@ const outputOptionsSchema = z.strictObject({
   entryFileNames: z.string().optional(),
   chunkFileNames: z.string().optional(),
   assetFileNames: z.string().optional(),
+  comments: z.boolean().optional(),
+  paths: z.record(z.string()).optional(),
+  legalComments: z
+    .literal('none')
+    .or(z.literal('inline'))
+    .or(z.literal('eof'))
+    .or(z.literal('linked'))
+    .or(z.literal('external'))
+    .optional(),
+  licenseReport: z
+    .strictObject({
+      fileName: z.string().optional(),
+      format: z.literal('text').or(z.literal('json')).optional(),
+    })
+    .optional(),
+  cssMinify: z.boolean().optional(),
+  cssTargets: z.string().array().optional(),
+  publicPath: z
+    .strictObject({
+      base: z.string().optional(),
+      runtime: z.string().optional(),
+      dynamicImports: z.boolean().optional(),
+    })
+    .optional(),
 })
 
 export type OutputOptions = z.infer<typeof outputOptionsSchema>
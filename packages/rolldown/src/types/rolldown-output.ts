@@ -47,11 +47,15 @@ export interface RolldownOutputChunk {
   map: SourceMap | null
   sourcemapFileName: string | null
   preliminaryFileName: string
+  cssFiles: string[]
 }
 
 function _assertRolldownOutputChunk() {
   type _ = TypeAssert<
-    IsPropertiesEqual<Omit<RolldownOutputChunk, 'modules' | 'map'>, OutputChunk>
+    IsPropertiesEqual<
+      Omit<RolldownOutputChunk, 'modules' | 'map' | 'cssFiles'>,
+      OutputChunk
+    >
   >
 }
 
@@ -15,6 +15,13 @@ export function normalizeOutputOptions(
     entryFileNames,
     chunkFileNames,
     assetFileNames,
+    comments,
+    paths,
+    legalComments,
+    licenseReport,
+    cssMinify,
+    cssTargets,
+    publicPath,
   } = opts
   return {
     dir: dir,
@@ -34,6 +41,13 @@ export function normalizeOutputOptions(
     entryFileNames: entryFileNames ?? '[name].js',
     chunkFileNames: chunkFileNames ?? '[name]-[hash].js',
     assetFileNames: assetFileNames ?? 'assets/[name]-[hash][extname]',
+    comments: comments ?? false,
+    paths,
+    legalComments,
+    licenseReport,
+    cssMinify,
+    cssTargets,
+    publicPath,
     plugins: [],
   }
 }
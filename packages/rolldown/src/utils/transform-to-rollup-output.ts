@@ -59,6 +59,9 @@ function transformToRollupOutputChunk(
     },
     sourcemapFileName: chunk.sourcemapFileName || null,
     preliminaryFileName: chunk.preliminaryFileName,
+    get cssFiles() {
+      return chunk.cssFiles
+    },
   }
 }
 